@@ -110,7 +110,9 @@ pub fn execute<
         .filter_map(|arg| match arg {
             CallArg::Pure(_) => None,
             CallArg::Object(ObjectArg::ImmOrOwnedObject((id, _, _)))
-            | CallArg::Object(ObjectArg::SharedObject(id)) => {
+            | CallArg::Object(ObjectArg::Receiving((id, _, _)))
+            | CallArg::Object(ObjectArg::SharedObject(id))
+            | CallArg::Object(ObjectArg::ReadonlySharedObject(id)) => {
                 Some(vec![(*id, state_view.read_object(id)?)])
             }
             CallArg::ObjVec(vec) => {
@@ -121,7 +123,9 @@ pub fn execute<
                     vec.iter()
                         .filter_map(|obj_arg| match obj_arg {
                             ObjectArg::ImmOrOwnedObject((id, _, _))
-                            | ObjectArg::SharedObject(id) => {
+                            | ObjectArg::Receiving((id, _, _))
+                            | ObjectArg::SharedObject(id)
+                            | ObjectArg::ReadonlySharedObject(id) => {
                                 Some((*id, state_view.read_object(id)?))
                             }
                         })
@@ -773,6 +777,23 @@ pub fn resolve_and_type_check(
     // Track the mapping from each input object to its Move type.
     // This will be needed latter in `check_child_object_of_shared_object`.
     let mut object_type_map = BTreeMap::new();
+
+    // A received object must be owned by one of the other objects passed to the call.
+    let parent_ids: BTreeSet<ObjectID> = args
+        .iter()
+        .flat_map(|arg| match arg {
+            CallArg::Object(obj_arg) => std::slice::from_ref(obj_arg),
+            CallArg::ObjVec(obj_args) => obj_args.as_slice(),
+            CallArg::Pure(_) => &[],
+        })
+        .filter_map(|obj_arg| match obj_arg {
+            ObjectArg::ImmOrOwnedObject((id, _, _))
+            | ObjectArg::SharedObject(id)
+            | ObjectArg::ReadonlySharedObject(id) => Some(*id),
+            ObjectArg::Receiving(_) => None,
+        })
+        .collect();
+
     let bcs_args = args
         .into_iter()
         .enumerate()
@@ -814,6 +835,21 @@ pub fn resolve_and_type_check(
                     type_check_struct(view, type_args, idx, arg_type, param_type)?;
                     o
                 }
+                CallArg::Object(ObjectArg::Receiving(ref_)) => {
+                    let (o, arg_type, param_type) = serialize_object(
+                        InputObjectKind::ImmOrOwnedMoveObject(ref_),
+                        idx,
+                        param_type,
+                        objects,
+                        &mut object_data,
+                        &mut mutable_ref_objects,
+                        &mut by_value_objects,
+                        &mut object_type_map,
+                    )?;
+                    type_check_struct(view, type_args, idx, arg_type, param_type)?;
+                    check_received_object(objects, &parent_ids, idx, ref_.0)?;
+                    o
+                }
                 CallArg::Object(ObjectArg::SharedObject(id)) => {
                     let (o, arg_type, param_type) = serialize_object(
                         InputObjectKind::SharedMoveObject(id),
@@ -828,6 +864,20 @@ pub fn resolve_and_type_check(
                     type_check_struct(view, type_args, idx, arg_type, param_type)?;
                     o
                 }
+                CallArg::Object(ObjectArg::ReadonlySharedObject(id)) => {
+                    let (o, arg_type, param_type) = serialize_object(
+                        InputObjectKind::ReadonlySharedMoveObject(id),
+                        idx,
+                        param_type,
+                        objects,
+                        &mut object_data,
+                        &mut mutable_ref_objects,
+                        &mut by_value_objects,
+                        &mut object_type_map,
+                    )?;
+                    type_check_struct(view, type_args, idx, arg_type, param_type)?;
+                    o
+                }
                 CallArg::ObjVec(vec) => {
                     if vec.is_empty() {
                         // bcs representation of the empty vector
@@ -838,11 +888,18 @@ pub fn resolve_and_type_check(
                     let mut res = vec![];
                     leb128::write::unsigned(&mut res, vec.len() as u64).unwrap();
                     for arg in vec {
+                        let received = match &arg {
+                            ObjectArg::Receiving((id, _, _)) => Some(*id),
+                            _ => None,
+                        };
                         let object_kind = match arg {
-                            ObjectArg::ImmOrOwnedObject(ref_) => {
+                            ObjectArg::ImmOrOwnedObject(ref_) | ObjectArg::Receiving(ref_) => {
                                 InputObjectKind::ImmOrOwnedMoveObject(ref_)
                             }
                             ObjectArg::SharedObject(id) => InputObjectKind::SharedMoveObject(id),
+                            ObjectArg::ReadonlySharedObject(id) => {
+                                InputObjectKind::ReadonlySharedMoveObject(id)
+                            }
                         };
                         let (o, arg_type, param_type) = serialize_object(
                             object_kind,
@@ -855,6 +912,9 @@ pub fn resolve_and_type_check(
                             &mut object_type_map,
                         )?;
                         type_check_struct(view, type_args, idx, arg_type, param_type)?;
+                        if let Some(id) = received {
+                            check_received_object(objects, &parent_ids, idx, id)?;
+                        }
                         res.extend(o);
                     }
                     res
@@ -1052,7 +1112,9 @@ fn serialize_object<'a>(
                 error,
             ));
         }
-        InputObjectKind::SharedMoveObject(_) if !object.is_shared() => {
+        InputObjectKind::SharedMoveObject(_) | InputObjectKind::ReadonlySharedMoveObject(_)
+            if !object.is_shared() =>
+        {
             let error = format!(
                 "Argument at index {} populated with an immutable or owned object id {} \
                         but an shared object was expected",
@@ -1066,6 +1128,22 @@ fn serialize_object<'a>(
                 error,
             ));
         }
+        InputObjectKind::ReadonlySharedMoveObject(_)
+            if matches!(param_type, SignatureToken::MutableReference(_)) =>
+        {
+            let error = format!(
+                "Argument at index {} populated with read-only shared object id {} \
+                        but the function takes it by mutable reference",
+                idx, object_id
+            );
+            return Err(ExecutionError::new_with_source(
+                ExecutionErrorKind::entry_argument_error(
+                    idx,
+                    EntryArgumentErrorKind::InvalidObjectByMuteRef,
+                ),
+                error,
+            ));
+        }
         _ => (),
     }
 
@@ -1184,6 +1262,29 @@ fn inner_param_type<'a>(
 ///   as the type implementing the trait or the trait itself).
 /// - For each shared object used by-value, the type of the shared object must be defined in the
 ///   same module as the entry function being called.
+/// Check that the received object `id` is owned by one of `parent_ids`, the other objects
+/// passed to the call.
+fn check_received_object(
+    objects: &BTreeMap<ObjectID, impl Borrow<Object>>,
+    parent_ids: &BTreeSet<ObjectID>,
+    idx: LocalIndex,
+    id: ObjectID,
+) -> Result<(), ExecutionError> {
+    match objects.get(&id).map(|o| o.borrow().owner) {
+        Some(Owner::ObjectOwner(parent)) if parent_ids.contains(&parent.into()) => Ok(()),
+        Some(Owner::ObjectOwner(parent)) => {
+            Err(ExecutionErrorKind::missing_object_owner(id, parent).into())
+        }
+        _ => Err(ExecutionError::new_with_source(
+            ExecutionErrorKind::entry_argument_error(
+                idx,
+                EntryArgumentErrorKind::ObjectKindMismatch,
+            ),
+            format!("Received object {id} is not owned by an object"),
+        )),
+    }
+}
+
 fn check_shared_object_rules(
     objects: &BTreeMap<ObjectID, impl Borrow<Object>>,
     by_value_objects: &BTreeSet<ObjectID>,
@@ -279,14 +279,22 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
                     admin_interface_port: utils::get_available_port(),
                     json_rpc_address: utils::available_local_socket_address(),
                     websocket_address: None,
+                    max_subscriptions_per_connection: None,
                     consensus_config: Some(consensus_config),
                     enable_event_processing: false,
+                    post_processing_threads: None,
+                    preload_packages: vec![],
+                    max_concurrent_executions: None,
+                    enabled_transaction_version: None,
                     enable_gossip: true,
                     enable_checkpoint: true,
                     enable_reconfig: false,
+                    validator_enable_read_rpc: false,
+                    enable_failure_notifications: false,
                     genesis: crate::node::Genesis::new(genesis.clone()),
                     grpc_load_shed: initial_accounts_config.grpc_load_shed,
                     grpc_concurrency_limit: initial_accounts_config.grpc_concurrency_limit,
+                    grpc_request_timeout_ms: None,
                     p2p_config,
                 }
             })
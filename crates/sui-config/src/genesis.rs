@@ -136,7 +136,7 @@ impl Genesis {
             .data
             .try_as_move()
             .expect("Sui System State object must be a Move object");
-        let result = bcs::from_bytes::<SuiSystemState>(move_object.contents())
+        let result = SuiSystemState::from_bcs_bytes(move_object.contents())
             .expect("Sui System State object deserialization cannot fail");
         result
     }
@@ -602,6 +602,7 @@ mod test {
         generate_proof_of_possession, get_key_pair_from_rng, AccountKeyPair, AuthorityKeyPair,
         NetworkKeyPair,
     };
+    use sui_types::sui_system_state::SuiSystemState;
 
     #[test]
     fn roundtrip() {
@@ -612,6 +613,21 @@ mod test {
         assert_eq!(genesis, from_s);
     }
 
+    #[test]
+    fn system_state_without_clock() {
+        let genesis = Builder::new().build();
+        let state = genesis.sui_system_object();
+        let bytes = bcs::to_bytes(&state).unwrap();
+        assert_eq!(SuiSystemState::from_bcs_bytes(&bytes).unwrap(), state);
+
+        // Genesis blobs from before the consensus commit clock lack its two trailing fields.
+        let legacy_bytes = &bytes[..bytes.len() - 2 * std::mem::size_of::<u64>()];
+        let legacy_state = SuiSystemState::from_bcs_bytes(legacy_bytes).unwrap();
+        assert_eq!(legacy_state.consensus_round, 0);
+        assert_eq!(legacy_state.consensus_commit_timestamp_ms, 0);
+        assert_eq!(legacy_state, state);
+    }
+
     #[test]
     fn ceremony() {
         let dir = tempfile::TempDir::new().unwrap();
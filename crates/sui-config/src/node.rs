@@ -13,7 +13,7 @@ use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use sui_types::base_types::SuiAddress;
+use sui_types::base_types::{ObjectID, SuiAddress};
 use sui_types::committee::StakeUnit;
 use sui_types::crypto::AccountKeyPair;
 use sui_types::crypto::AuthorityKeyPair;
@@ -53,6 +53,10 @@ pub struct NodeConfig {
     pub json_rpc_address: SocketAddr,
     #[serde(default = "default_websocket_address")]
     pub websocket_address: Option<SocketAddr>,
+    /// The most subscriptions a single websocket connection may hold at once. Subscribing
+    /// past it fails with a JSON-RPC error. Unlimited if unset.
+    #[serde(default)]
+    pub max_subscriptions_per_connection: Option<usize>,
 
     #[serde(default = "default_metrics_address")]
     pub metrics_address: SocketAddr,
@@ -65,6 +69,27 @@ pub struct NodeConfig {
     #[serde(default)]
     pub enable_event_processing: bool,
 
+    /// Run the transaction post-processing (indexing and event processing) on a dedicated
+    /// runtime with this many worker threads, isolating it from request handling. Runs on
+    /// the node's main runtime if unset.
+    #[serde(default)]
+    pub post_processing_threads: Option<usize>,
+
+    /// Packages whose modules are loaded into the Move module cache at startup, before the
+    /// node starts serving, so that the first calls into them are not slowed down.
+    #[serde(default)]
+    pub preload_packages: Vec<ObjectID>,
+
+    /// Maximum number of pending certificates the execution driver executes at once.
+    /// Unbounded if unset.
+    #[serde(default)]
+    pub max_concurrent_executions: Option<usize>,
+
+    /// The newest transaction format version the node signs, see
+    /// `sui_types::messages::MAX_TRANSACTION_VERSION`. Only the base version if unset.
+    #[serde(default)]
+    pub enabled_transaction_version: Option<u64>,
+
     #[serde(default)]
     pub enable_gossip: bool,
 
@@ -74,12 +99,27 @@ pub struct NodeConfig {
     #[serde(default)]
     pub enable_reconfig: bool,
 
+    /// Expose the read-only JSON-RPC APIs on a validator. Transaction execution is never
+    /// exposed by validators.
+    #[serde(default)]
+    pub validator_enable_read_rpc: bool,
+
+    /// Publish every transaction whose execution fails on a broadcast channel, see
+    /// `SuiNode::subscribe_to_failures`.
+    #[serde(default)]
+    pub enable_failure_notifications: bool,
+
     #[serde(default)]
     pub grpc_load_shed: Option<bool>,
 
     #[serde(default = "default_concurrency_limit")]
     pub grpc_concurrency_limit: Option<usize>,
 
+    /// Per-request timeout for the gRPC server. Requests that take longer are
+    /// aborted with a timeout status. No timeout is applied if unset.
+    #[serde(default)]
+    pub grpc_request_timeout_ms: Option<u64>,
+
     #[serde(default)]
     pub p2p_config: P2pConfig,
 
@@ -118,14 +118,22 @@ impl NetworkConfig {
             } else {
                 None
             },
+            max_subscriptions_per_connection: None,
             consensus_config: None,
             enable_event_processing,
+            post_processing_threads: None,
+            preload_packages: vec![],
+            max_concurrent_executions: None,
+            enabled_transaction_version: None,
             enable_gossip: true,
             enable_checkpoint: true,
             enable_reconfig: false,
+            validator_enable_read_rpc: false,
+            enable_failure_notifications: false,
             genesis: validator_config.genesis.clone(),
             grpc_load_shed: None,
             grpc_concurrency_limit: None,
+            grpc_request_timeout_ms: None,
             p2p_config,
         }
     }
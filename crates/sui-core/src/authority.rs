@@ -9,7 +9,7 @@ use std::{
     collections::{HashMap, VecDeque},
     pin::Pin,
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
 };
@@ -21,7 +21,9 @@ use chrono::prelude::*;
 use fastcrypto::traits::KeyPair;
 use futures::stream::{self, Stream};
 use move_bytecode_utils::module_cache::SyncModuleCache;
-use move_core_types::{language_storage::ModuleId, resolver::ModuleResolver};
+use move_core_types::{
+    identifier::Identifier, language_storage::ModuleId, resolver::ModuleResolver,
+};
 use move_vm_runtime::{move_vm::MoveVM, native_functions::NativeFunctionTable};
 use parking_lot::Mutex;
 use prometheus::{
@@ -121,10 +123,17 @@ mod authority_store;
 
 pub const MAX_ITEMS_LIMIT: u64 = 1_000;
 const BROADCAST_CAPACITY: usize = 10_000;
+/// Number of transactions whose resolved input objects are kept by `cached_input_objects`.
+const INPUT_OBJECTS_CACHE_SIZE: usize = 10_000;
 
 pub(crate) const MAX_TX_RECOVERY_RETRY: u32 = 3;
 type CertTxGuard<'a> = DBTxGuard<'a, CertifiedTransaction>;
 
+/// Broadcasts the digest and failure status of every certificate whose execution fails.
+pub type ExecutionFailureSender = broadcast::Sender<(TransactionDigest, ExecutionFailureStatus)>;
+pub type ExecutionFailureReceiver =
+    broadcast::Receiver<(TransactionDigest, ExecutionFailureStatus)>;
+
 pub type ReconfigConsensusMessage = (
     AuthorityKeyPair,
     NetworkKeyPair,
@@ -156,6 +165,7 @@ pub struct AuthorityMetrics {
     skipped_consensus_txns: IntCounter,
     handle_consensus_duration_mcs: IntCounter,
     verify_narwhal_transaction_duration_mcs: IntCounter,
+    pub(crate) consensus_round_trip_latency: Histogram,
 
     pub follower_items_streamed: IntCounter,
     pub follower_items_loaded: IntCounter,
@@ -322,6 +332,13 @@ impl AuthorityMetrics {
                 registry,
             )
             .unwrap(),
+            consensus_round_trip_latency: register_histogram_with_registry!(
+                "consensus_round_trip_latency",
+                "Latency from submitting a transaction to consensus until it is sequenced",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             follower_items_streamed: register_int_counter_with_registry!(
                 "follower_items_streamed",
                 "Number of transactions/signed batches streamed to followers",
@@ -465,6 +482,12 @@ pub struct AuthorityState {
     pub event_handler: Option<Arc<EventHandler>>,
     pub transaction_streamer: Option<Arc<TransactionStreamer>>,
 
+    /// Notified of every failed execution, if enabled.
+    failure_notifier: Option<ExecutionFailureSender>,
+
+    /// Notified of the digest of every executed certificate.
+    executed_digests: broadcast::Sender<TransactionDigest>,
+
     /// The checkpoint store
     pub checkpoints: Arc<Mutex<CheckpointStore>>,
 
@@ -486,6 +509,13 @@ pub struct AuthorityState {
 
     /// A channel to tell consensus to reconfigure.
     tx_reconfigure_consensus: mpsc::Sender<ReconfigConsensusMessage>,
+
+    /// Set while an epoch change is in progress, i.e. while the committee may be changing.
+    reconfiguring: AtomicBool,
+
+    /// The newest transaction format version this authority signs, see
+    /// `MAX_TRANSACTION_VERSION`.
+    enabled_transaction_version: AtomicU64,
 }
 
 /// The authority state encapsulates all state, drives execution, and ensures safety.
@@ -499,11 +529,32 @@ impl AuthorityState {
         !self.committee.load().authority_exists(&self.name)
     }
 
+    /// Returns the input objects of `transaction`, memoized by its digest.
+    /// This is the same cache the input checks use when signing and executing transactions.
+    pub(crate) fn cached_input_objects<T>(
+        &self,
+        transaction: &TransactionEnvelope<T>,
+    ) -> SuiResult<Vec<InputObjectKind>> {
+        self.database.cached_input_objects(transaction)
+    }
+
     /// Get a broadcast receiver for updates
     pub fn subscribe_batch(&self) -> BroadcastReceiver {
         self.batch_channels.subscribe()
     }
 
+    /// Get a broadcast receiver for failed executions, or None if failure notifications
+    /// are not enabled on this authority.
+    pub fn subscribe_to_execution_failures(&self) -> Option<ExecutionFailureReceiver> {
+        self.failure_notifier.as_ref().map(|tx| tx.subscribe())
+    }
+
+    /// Get a broadcast receiver for the digest of every certificate executed from now on.
+    /// This is cheaper than subscribing to effects when only the digests are needed.
+    pub fn subscribe_to_executed_digests(&self) -> broadcast::Receiver<TransactionDigest> {
+        self.executed_digests.subscribe()
+    }
+
     pub fn epoch(&self) -> EpochId {
         self.committee.load().epoch
     }
@@ -539,6 +590,8 @@ impl AuthorityState {
             return Err(SuiError::ValidatorHaltedAtEpochEnd);
         }
 
+        self.check_transaction_version(&transaction.signed_data.data.kind)?;
+
         let (_gas_status, input_objects) =
             transaction_input_checker::check_transaction_input(&self.database, &transaction)
                 .await?;
@@ -704,7 +757,9 @@ impl AuthorityState {
             .instrument(span)
             .await?;
 
-        if !certificate.is_system_tx() && self.is_cert_awaiting_sequencing(certificate)? {
+        if !certificate.signed_data.data.kind.is_change_epoch_tx()
+            && self.is_cert_awaiting_sequencing(certificate)?
+        {
             debug!("shared object cert has not been sequenced by narwhal");
             return Err(SuiError::SharedObjectLockNotSetError);
         }
@@ -786,9 +841,9 @@ impl AuthorityState {
             return Ok(info);
         }
 
-        // We also bypass validator halt if this is the system transaction.
+        // We also bypass validator halt if this is the epoch change transaction.
         // TODO: Shared object transactions should also bypass validator halt.
-        bypass_validator_halt |= certificate.is_system_tx();
+        bypass_validator_halt |= certificate.signed_data.data.kind.is_change_epoch_tx();
 
         if self.is_halted() && !bypass_validator_halt {
             tx_guard.release();
@@ -848,6 +903,15 @@ impl AuthorityState {
         // commit_certificate finished, the tx is fully committed to the store.
         tx_guard.commit_tx();
 
+        if let (Some(failure_notifier), ExecutionStatus::Failure { error }) =
+            (&self.failure_notifier, &signed_effects.effects.status)
+        {
+            // An error here only means that there are currently no subscribers.
+            let _ = failure_notifier.send((digest, error.clone()));
+        }
+        // Likewise, an error only means that there are no subscribers.
+        let _ = self.executed_digests.send(digest);
+
         // Update metrics.
         self.metrics.total_effects.inc();
         self.metrics.total_certs.inc();
@@ -900,7 +964,8 @@ impl AuthorityState {
             // If the transaction contains shared objects, we need to ensure they have been scheduled
             // for processing by the consensus protocol.
             // There is no need to go through consensus for system transactions that can
-            // only be executed at a time when consensus is turned off.
+            // only be executed at a time when consensus is turned off. Other system
+            // transactions, e.g. the consensus commit prologue, are sequenced like any other.
             // TODO: Add some assert here to make sure consensus is indeed off with
             // is_change_epoch_tx.
             self.check_shared_locks(&transaction_digest, &shared_object_refs)
@@ -928,6 +993,9 @@ impl AuthorityState {
                 self.epoch(),
             );
 
+        // Input checking bounds the inputs before signing, so this holds for any certificate.
+        debug_assert!(effects.validate_dependency_bound().is_ok());
+
         // TODO: Distribute gas charge and rebate, which can be retrieved from effects.
         let signed_effects = effects.to_sign_effects(self.epoch(), &self.name, &*self.secret);
         Ok((inner_temp_store, signed_effects))
@@ -939,6 +1007,7 @@ impl AuthorityState {
         transaction_digest: TransactionDigest,
     ) -> Result<SuiTransactionEffects, anyhow::Error> {
         transaction.verify()?;
+        self.check_transaction_version(&transaction.signed_data.data.kind)?;
         let (gas_status, input_objects) =
             transaction_input_checker::check_transaction_input(&self.database, transaction).await?;
         let shared_object_refs = input_objects.filter_shared_objects();
@@ -989,9 +1058,7 @@ impl AuthorityState {
     ) -> SuiResult {
         indexes.index_tx(
             cert.sender_address(),
-            cert.signed_data
-                .data
-                .input_objects()?
+            self.cached_input_objects(cert)?
                 .iter()
                 .map(|o| o.object_id()),
             effects
@@ -1315,6 +1382,59 @@ impl AuthorityState {
         }
     }
 
+    /// Return the effects of the transactions in the checkpoints from `checkpoint` onwards, in
+    /// checkpoint order, together with the checkpoint to continue from: the one after the last
+    /// returned checkpoint, or `checkpoint` itself if none was returned. Pages hold whole
+    /// checkpoints and stop before exceeding `limit` effects, except that the first checkpoint
+    /// is always included so that the cursor advances. A page also ends before the first
+    /// checkpoint whose effects are not all stored locally yet, so that it is retried later
+    /// rather than skipped.
+    pub fn get_effects_since(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        limit: usize,
+    ) -> SuiResult<(Vec<SignedTransactionEffects>, CheckpointSequenceNumber)> {
+        let mut page = Vec::new();
+        {
+            let checkpoints = self.checkpoints.lock();
+            let mut size = 0;
+            for (seq, contents) in checkpoints
+                .tables
+                .checkpoint_contents
+                .iter()
+                .skip_to(&checkpoint)?
+            {
+                if !page.is_empty() && size + contents.size() > limit {
+                    break;
+                }
+                size += contents.size();
+                let digests: Vec<_> = contents.iter().map(|digests| digests.transaction).collect();
+                page.push((seq, digests));
+            }
+        }
+
+        let mut effects = Vec::new();
+        let mut next_checkpoint = checkpoint;
+        for (seq, digests) in page {
+            let checkpoint_effects: Option<Vec<_>> = self
+                .database
+                .multi_get_effects_envelopes(&digests)?
+                .into_iter()
+                .collect();
+            match checkpoint_effects {
+                Some(checkpoint_effects) => {
+                    effects.extend(checkpoint_effects);
+                    next_checkpoint = seq + 1;
+                }
+                None => {
+                    debug!(checkpoint = ?seq, "effects of checkpoint not yet stored locally");
+                    break;
+                }
+            }
+        }
+        Ok((effects, next_checkpoint))
+    }
+
     pub async fn handle_checkpoint_streaming(
         &self,
         _request: CheckpointStreamRequest,
@@ -1394,9 +1514,17 @@ impl AuthorityState {
                 (committee.epoch, Some(committee))
             }
         };
+        // The system state only knows the reference gas price of the current epoch.
+        let reference_gas_price = self
+            .database
+            .get_sui_system_state_object()
+            .ok()
+            .filter(|system_state| system_state.epoch == epoch)
+            .map(|system_state| system_state.reference_gas_price);
         Ok(CommitteeInfoResponse {
             epoch,
             committee_info: committee.map(|c| c.voting_rights),
+            reference_gas_price,
         })
     }
 
@@ -1411,6 +1539,7 @@ impl AuthorityState {
         indexes: Option<Arc<IndexStore>>,
         event_store: Option<Arc<EventStoreType>>,
         transaction_streamer: Option<Arc<TransactionStreamer>>,
+        failure_notifier: Option<ExecutionFailureSender>,
         checkpoints: Arc<Mutex<CheckpointStore>>,
         genesis: &Genesis,
         prometheus_registry: &prometheus::Registry,
@@ -1452,6 +1581,8 @@ impl AuthorityState {
             module_cache: Arc::new(SyncModuleCache::new(ResolverWrapper(store.clone()))),
             event_handler,
             transaction_streamer,
+            failure_notifier,
+            executed_digests: broadcast::channel(BROADCAST_CAPACITY).0,
             checkpoints,
             committee_store,
             batch_channels: tx,
@@ -1462,6 +1593,8 @@ impl AuthorityState {
             consensus_guardrail: AtomicUsize::new(0),
             metrics: Arc::new(AuthorityMetrics::new(prometheus_registry)),
             tx_reconfigure_consensus,
+            reconfiguring: AtomicBool::new(false),
+            enabled_transaction_version: AtomicU64::new(BASE_TRANSACTION_VERSION),
         };
 
         // Process tx recovery log first, so that the batch and checkpoint recovery (below)
@@ -1576,6 +1709,7 @@ impl AuthorityState {
             None,
             None,
             None,
+            Some(broadcast::channel(BROADCAST_CAPACITY).0),
             Arc::new(Mutex::new(checkpoints)),
             genesis,
             &prometheus::Registry::new(),
@@ -1665,6 +1799,48 @@ impl AuthorityState {
         self.batch_notifier.unpause();
     }
 
+    /// Whether an epoch change is in progress. Clients should hold off submitting
+    /// transactions until it completes.
+    pub fn is_reconfiguring(&self) -> bool {
+        self.reconfiguring.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn set_reconfiguring(&self, reconfiguring: bool) {
+        self.reconfiguring.store(reconfiguring, Ordering::Release);
+    }
+
+    /// The newest transaction format version this authority signs.
+    pub fn enabled_transaction_version(&self) -> u64 {
+        self.enabled_transaction_version.load(Ordering::Acquire)
+    }
+
+    /// Start signing transactions that need up to `version`. Operators should only do so once
+    /// every validator in the committee runs a binary that supports it.
+    pub fn enable_transaction_version(&self, version: u64) -> SuiResult {
+        fp_ensure!(
+            version <= MAX_TRANSACTION_VERSION,
+            SuiError::UnsupportedTransactionVersion {
+                required: version,
+                enabled: MAX_TRANSACTION_VERSION,
+            }
+        );
+        self.enabled_transaction_version
+            .store(version, Ordering::Release);
+        Ok(())
+    }
+
+    /// Reject `kind` if it needs a newer transaction format version than the enabled one.
+    /// Validators must also check this before creating a system transaction.
+    pub fn check_transaction_version(&self, kind: &TransactionKind) -> SuiResult {
+        let required = kind.required_version();
+        let enabled = self.enabled_transaction_version();
+        fp_ensure!(
+            required <= enabled,
+            SuiError::UnsupportedTransactionVersion { required, enabled }
+        );
+        Ok(())
+    }
+
     pub fn db(&self) -> Arc<AuthorityStore> {
         self.database.clone()
     }
@@ -1677,6 +1853,45 @@ impl AuthorityState {
         self.database.get_object(object_id)
     }
 
+    /// Load every module of the given packages into `module_cache`, so that the first
+    /// requests touching them don't pay for reading and deserializing the modules.
+    /// Returns the number of modules loaded.
+    pub async fn preload_packages(&self, packages: &[ObjectID]) -> SuiResult<usize> {
+        let mut loaded = 0;
+        for package_id in packages {
+            let object = self
+                .get_object(package_id)
+                .await?
+                .ok_or(SuiError::ObjectNotFound {
+                    object_id: *package_id,
+                })?;
+            let package = object
+                .data
+                .try_as_package()
+                .ok_or(SuiError::MoveObjectAsPackage {
+                    object_id: *package_id,
+                })?;
+            for name in package.serialized_module_map().keys() {
+                let module_id = ModuleId::new(
+                    (*package_id).into(),
+                    Identifier::new(name.as_str()).map_err(|e| SuiError::ModuleLoadFailure {
+                        error: e.to_string(),
+                    })?,
+                );
+                self.module_cache
+                    .get_module_by_id(&module_id)
+                    .map_err(|e| SuiError::ModuleLoadFailure {
+                        error: e.to_string(),
+                    })?
+                    .ok_or(SuiError::ModuleNotFound {
+                        module_name: name.clone(),
+                    })?;
+                loaded += 1;
+            }
+        }
+        Ok(loaded)
+    }
+
     pub async fn get_framework_object_ref(&self) -> SuiResult<ObjectRef> {
         Ok(self
             .get_object(&SUI_FRAMEWORK_ADDRESS.into())
@@ -2304,6 +2519,7 @@ impl AuthorityState {
                 //      .await
                 //      .expect("Failed to reconfigure consensus");
                 // ```
+                // with `self.set_reconfiguring(true)` raised before the send.
                 let _tx_reconfigure_consensus = &self.tx_reconfigure_consensus;
 
                 Ok(())
@@ -6,10 +6,11 @@ use super::{
     *,
 };
 use crate::authority::authority_store_tables::ExecutionIndicesWithHash;
+use lru::LruCache;
 use rocksdb::Options;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::iter;
 use std::path::Path;
 use std::sync::atomic::AtomicU64;
@@ -68,6 +69,9 @@ pub struct SuiDataStore<S> {
 
     pub(crate) perpetual_tables: AuthorityPerpetualTables<S>,
     pub(crate) epoch_tables: AuthorityEpochTables<S>,
+
+    /// Resolved input objects of recently seen transactions, keyed by transaction digest.
+    pub(crate) input_objects_cache: Mutex<LruCache<TransactionDigest, Vec<InputObjectKind>>>,
 }
 
 impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
@@ -103,7 +107,25 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
             pending_notifier: Arc::new(Notify::new()),
             perpetual_tables,
             epoch_tables,
+            input_objects_cache: Mutex::new(LruCache::new(INPUT_OBJECTS_CACHE_SIZE)),
+        }
+    }
+
+    /// Returns the input objects of `transaction`, memoized by its digest.
+    /// Errors are not cached, so a malformed transaction is re-checked on every call.
+    pub(crate) fn cached_input_objects<T>(
+        &self,
+        transaction: &TransactionEnvelope<T>,
+    ) -> SuiResult<Vec<InputObjectKind>> {
+        let digest = transaction.digest();
+        if let Some(objects) = self.input_objects_cache.lock().get(digest) {
+            return Ok(objects.clone());
         }
+        let objects = transaction.signed_data.data.input_objects()?;
+        self.input_objects_cache
+            .lock()
+            .put(*digest, objects.clone());
+        Ok(objects)
     }
 
     pub async fn acquire_tx_guard(&self, cert: &CertifiedTransaction) -> SuiResult<CertTxGuard> {
@@ -147,6 +169,18 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
             })
     }
 
+    /// Batch version of `get_effects`, returning the effects envelopes as stored, i.e. with
+    /// the signature of this authority.
+    pub fn multi_get_effects_envelopes(
+        &self,
+        transaction_digests: &[TransactionDigest],
+    ) -> SuiResult<Vec<Option<TransactionEffectsEnvelope<S>>>> {
+        Ok(self
+            .perpetual_tables
+            .effects
+            .multi_get(transaction_digests)?)
+    }
+
     /// Returns true if we have an effects structure for this transaction digest
     pub fn effects_exists(&self, transaction_digest: &TransactionDigest) -> SuiResult<bool> {
         self.perpetual_tables
@@ -328,9 +362,9 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
         let mut errors = Vec::new();
         for kind in objects {
             let obj = match kind {
-                InputObjectKind::MovePackage(id) | InputObjectKind::SharedMoveObject(id) => {
-                    self.get_object(id)?
-                }
+                InputObjectKind::MovePackage(id)
+                | InputObjectKind::SharedMoveObject(id)
+                | InputObjectKind::ReadonlySharedMoveObject(id) => self.get_object(id)?,
                 InputObjectKind::ImmOrOwnedMoveObject(objref) => {
                     self.get_object_by_key(&objref.0, objref.1)?
                 }
@@ -360,7 +394,8 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
         for kind in objects {
             let obj = match kind {
                 InputObjectKind::MovePackage(id) => self.get_object(id)?,
-                InputObjectKind::SharedMoveObject(id) => match shared_locks.get(id) {
+                InputObjectKind::SharedMoveObject(id)
+                | InputObjectKind::ReadonlySharedMoveObject(id) => match shared_locks.get(id) {
                     Some(version) => self.get_object_by_key(id, *version)?,
                     None => {
                         errors.push(SuiError::SharedObjectLockNotSetError);
@@ -1137,6 +1172,16 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
         // Make an iterator to save the certificate.
         let transaction_digest = *certificate.digest();
 
+        // Read-only shared objects are read at the version they are sequenced at but never
+        // written, so they do not advance the next version to assign.
+        let mutable_ids: HashSet<_> = certificate
+            .signed_data
+            .data
+            .shared_objects_with_mutability()
+            .into_iter()
+            .filter_map(|(id, mutable)| mutable.then_some(id))
+            .collect();
+
         // Make an iterator to update the locks of the transaction's shared objects.
         let ids = certificate.shared_input_objects();
         let versions = self.epoch_tables.next_object_versions.multi_get(ids)?;
@@ -1152,11 +1197,12 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
                 let next_version = version.increment();
 
                 let sequenced = ((transaction_digest, *id), version);
-                let scheduled = (id, next_version);
+                let scheduled = mutable_ids.contains(id).then_some((id, next_version));
 
                 (sequenced, scheduled)
             })
             .unzip();
+        let schedule_to_write: Vec<_> = schedule_to_write.into_iter().flatten().collect();
 
         trace!(tx_digest = ?transaction_digest,
                ?sequenced_to_write, ?schedule_to_write,
@@ -1378,7 +1424,7 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
             .data
             .try_as_move()
             .expect("Sui System State object must be a Move object");
-        let result = bcs::from_bytes::<SuiSystemState>(move_object.contents())
+        let result = SuiSystemState::from_bcs_bytes(move_object.contents())
             .expect("Sui System State object deserialization cannot fail");
         Ok(result)
     }
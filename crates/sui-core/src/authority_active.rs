@@ -374,8 +374,17 @@ where
 
     /// Spawn pending certificate execution process
     pub async fn spawn_execute_process(self: Arc<Self>) -> JoinHandle<()> {
+        self.spawn_execute_process_with_limit(None).await
+    }
+
+    /// Same as `spawn_execute_process`, but with at most `max_concurrent_executions`
+    /// pending certificates executing at once, if set.
+    pub async fn spawn_execute_process_with_limit(
+        self: Arc<Self>,
+        max_concurrent_executions: Option<usize>,
+    ) -> JoinHandle<()> {
         tokio::task::spawn(async move {
-            execution_process(self).await;
+            execution_process(self, max_concurrent_executions).await;
         })
     }
 
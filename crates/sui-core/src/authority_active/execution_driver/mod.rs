@@ -1,19 +1,23 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashSet, sync::Arc};
-use sui_types::{base_types::TransactionDigest, error::SuiResult, messages::CertifiedTransaction};
-use tracing::{debug, info};
+use std::{collections::HashSet, future::Future, sync::Arc};
+use sui_types::{
+    base_types::TransactionDigest,
+    error::{SuiError, SuiResult},
+    messages::{CertifiedTransaction, ExecutionStatus, TransactionEffects},
+};
+use tracing::{debug, info, warn};
 
 use crate::authority::AuthorityState;
 use crate::authority_client::AuthorityAPI;
+use crate::node_sync::SyncResult;
 
-use futures::{stream, StreamExt};
+use futures::{future, Stream, StreamExt};
+use tokio::sync::Semaphore;
 
 use super::ActiveAuthority;
 
-use tap::TapFallible;
-
 #[cfg(test)]
 pub(crate) mod tests;
 
@@ -47,8 +51,11 @@ impl PendCertificateForExecution for PendCertificateForExecutionNoop {
 
 /// When a notification that a new pending transaction is received we activate
 /// processing the transaction in a loop.
-pub async fn execution_process<A>(active_authority: Arc<ActiveAuthority<A>>)
-where
+/// At most `max_concurrent_executions` certificates are executed at once, if set.
+pub async fn execution_process<A>(
+    active_authority: Arc<ActiveAuthority<A>>,
+    max_concurrent_executions: Option<usize>,
+) where
     A: AuthorityAPI + Send + Sync + 'static + Clone,
 {
     info!("Start pending certificates execution process.");
@@ -68,7 +75,7 @@ where
             tracing::error!("Error processing tx recovery log: {:?}", err);
         }
 
-        match execute_pending(active_authority.clone()).await {
+        match execute_pending(active_authority.clone(), max_concurrent_executions).await {
             Err(err) => {
                 tracing::error!("Error in pending execution subsystem: {err}");
                 // The above should not return an error if the DB works, and we are connected to
@@ -91,7 +98,10 @@ where
 
 /// Reads all pending transactions as a block and executes them.
 /// Returns whether all pending transactions succeeded.
-async fn execute_pending<A>(active_authority: Arc<ActiveAuthority<A>>) -> SuiResult<bool>
+async fn execute_pending<A>(
+    active_authority: Arc<ActiveAuthority<A>>,
+    max_concurrent_executions: Option<usize>,
+) -> SuiResult<bool>
 where
     A: AuthorityAPI + Send + Sync + 'static + Clone,
 {
@@ -119,27 +129,29 @@ where
         .remove_pending_digests(indexes_to_delete)?;
 
     // Send them for execution
-    let epoch = active_authority.state.committee.load().epoch;
+    let state = &active_authority.state;
+    let epoch = state.committee.load().epoch;
     let sync_handle = active_authority.clone().node_sync_handle();
-    let executed: Vec<_> = sync_handle
-        // map to extract digest
-        .handle_execution_request(
-            epoch,
-            pending_transactions.iter().map(|(_, digest)| *digest),
-        )
-        .await?
-        // zip results back together with seq
-        .zip(stream::iter(pending_transactions.iter()))
-        // filter out errors
-        .filter_map(|(result, (seq, digest))| async move {
-            result
-                .tap_err(|e| info!(?seq, ?digest, "certificate execution failed: {}", e))
-                .tap_ok(|_| debug!(?seq, ?digest, "certificate execution complete"))
-                .ok()
-                .map(|_| seq)
-        })
-        .collect()
-        .await;
+    let sync_handle = &sync_handle;
+    let digests: Vec<_> = pending_transactions
+        .iter()
+        .map(|(_, digest)| *digest)
+        .collect();
+    let results = execute_with_limit(&digests, max_concurrent_executions, |batch| {
+        sync_handle.handle_execution_request(epoch, batch.iter().copied())
+    })
+    .await;
+
+    let mut executed = Vec::new();
+    for (result, (seq, digest)) in results.into_iter().zip(pending_transactions.iter()) {
+        match result {
+            Ok(_) => {
+                debug!(?seq, ?digest, "certificate execution complete");
+                executed.push(*seq);
+            }
+            Err(e) => info!(?seq, ?digest, "certificate execution failed: {}", e),
+        }
+    }
 
     let pending_count = pending_transactions.len();
     let executed_count = executed.len();
@@ -153,3 +165,50 @@ where
 
     Ok(pending_count == executed_count)
 }
+
+/// Sends `digests` for execution through `execute` as batched requests, holding a permit per
+/// digest so that at most `max_concurrent` of them are in flight at once, if set. Returns one
+/// result per digest, in order; a digest whose batch yielded no result was not executed.
+async fn execute_with_limit<'a, F, Fut, S>(
+    digests: &'a [TransactionDigest],
+    max_concurrent: Option<usize>,
+    execute: F,
+) -> Vec<SyncResult>
+where
+    F: Fn(&'a [TransactionDigest]) -> Fut,
+    Fut: Future<Output = SuiResult<S>>,
+    S: Stream<Item = SyncResult>,
+{
+    let batch_size = max_concurrent.unwrap_or(digests.len()).max(1);
+    let limit = Semaphore::new(batch_size);
+    let limit = &limit;
+    let execute = &execute;
+    let batches = future::join_all(digests.chunks(batch_size).map(|batch| async move {
+        // Unwrap ok because the semaphore is never closed, and a batch never holds more
+        // permits than the semaphore was created with.
+        let _permits = limit.acquire_many(batch.len() as u32).await.unwrap();
+        let results: Vec<_> = match execute(batch).await {
+            Ok(results) => results.collect().await,
+            Err(err) => batch.iter().map(|_| Err(err.clone())).collect(),
+        };
+        let missing = batch[results.len().min(batch.len())..]
+            .iter()
+            .map(|digest| Err(SuiError::CertificateNotExecuted { digest: *digest }));
+        results.into_iter().chain(missing).collect::<Vec<_>>()
+    }))
+    .await;
+    batches.into_iter().flatten().collect()
+}
+
+/// Emits a structured event if the effects of an executed certificate record a failure,
+/// so that operators can tie the transaction digest to its failure status from the logs.
+pub(crate) fn log_failed_execution(cert: &CertifiedTransaction, effects: &TransactionEffects) {
+    if let ExecutionStatus::Failure { error } = &effects.status {
+        warn!(
+            tx_digest = ?cert.digest(),
+            error_code = error.error_code(),
+            kind = cert.signed_data.data.kind_as_str(),
+            "transaction execution failed: {error}"
+        );
+    }
+}
@@ -11,14 +11,20 @@ use sui_types::crypto::AccountKeyPair;
 use sui_types::{crypto::get_key_pair, messages::ExecutionStatus, object::Object};
 
 //use super::super::AuthorityState;
+use crate::authority::authority_tests::{init_state_with_objects, send_and_confirm_transaction};
 use crate::authority_aggregator::authority_aggregator_tests::{
     crate_object_move_transaction, do_cert, do_transaction, extract_cert, get_latest_ref,
     init_local_authorities, transfer_object_move_transaction,
 };
 use crate::checkpoints::checkpoint_tests::checkpoint_tests_setup;
-use crate::test_utils::wait_for_tx;
+use crate::test_utils::{to_sender_signed_transaction, wait_for_tx};
 
+use sui_types::base_types::dbg_addr;
+use sui_types::messages::TransactionData;
 use tracing::info;
+use tracing_test::traced_test;
+
+use super::{execute_with_limit, log_failed_execution};
 
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn pending_exec_storage_notify() {
@@ -275,3 +281,89 @@ async fn test_parent_cert_exec() {
         .signed_effects
         .unwrap();
 }
+
+#[tokio::test]
+#[traced_test]
+async fn test_log_failed_execution() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+    let authority_state = init_state_with_objects(vec![gas_object.clone()]).await;
+
+    // Transferring more than the gas coin holds fails during execution.
+    let data = TransactionData::new_transfer_sui(
+        dbg_addr(2),
+        sender,
+        Some(u64::MAX),
+        gas_object.compute_object_reference(),
+        10000,
+    );
+    let transaction = to_sender_signed_transaction(data, &sender_key);
+    let digest = *transaction.digest();
+    let response = send_and_confirm_transaction(&authority_state, transaction)
+        .await
+        .unwrap();
+    let certificate = response.certified_transaction.unwrap();
+    let effects = response.signed_effects.unwrap().effects;
+    let error_code = effects.status.err().unwrap().error_code();
+
+    log_failed_execution(&certificate, &effects);
+    assert!(logs_contain("transaction execution failed"));
+    assert!(logs_contain(&format!("tx_digest={:?}", digest)));
+    assert!(logs_contain(&format!("error_code=\"{}\"", error_code)));
+    assert!(logs_contain("kind=\"TransferSui\""));
+}
+
+#[tokio::test]
+async fn test_execute_with_limit() {
+    use futures::stream::FuturesOrdered;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use sui_types::base_types::TransactionDigest;
+    use sui_types::error::SuiError;
+
+    use crate::node_sync::SyncStatus;
+
+    let digests: Vec<_> = (0..3).map(|_| TransactionDigest::random()).collect();
+
+    // Each digest takes a while to execute, so that overlapping executions are observable.
+    async fn max_in_flight(digests: &[TransactionDigest], max_concurrent: Option<usize>) -> usize {
+        let in_flight = AtomicUsize::new(0);
+        let max_seen = AtomicUsize::new(0);
+        let results = execute_with_limit(digests, max_concurrent, |batch| {
+            let (in_flight, max_seen) = (&in_flight, &max_seen);
+            async move {
+                let executions: FuturesOrdered<_> = batch
+                    .iter()
+                    .map(|_| async move {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_seen.fetch_max(current, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        Ok(SyncStatus::CertExecuted)
+                    })
+                    .collect();
+                Ok(executions)
+            }
+        })
+        .await;
+        assert_eq!(results.len(), digests.len());
+        assert!(results.iter().all(|result| result.is_ok()));
+        max_seen.load(Ordering::SeqCst)
+    }
+
+    // With a cap of one, executions are serialized.
+    assert_eq!(max_in_flight(&digests, Some(1)).await, 1);
+    assert_eq!(max_in_flight(&digests, None).await, 3);
+
+    // A digest the batched request yields no result for is reported as not executed.
+    let results = execute_with_limit(&digests, None, |batch| async move {
+        Ok(futures::stream::iter(
+            batch[..2].iter().map(|_| Ok(SyncStatus::CertExecuted)),
+        ))
+    })
+    .await;
+    assert!(results[..2].iter().all(|result| result.is_ok()));
+    assert!(matches!(
+        &results[2],
+        Err(SuiError::CertificateNotExecuted { digest }) if *digest == digests[2]
+    ));
+}
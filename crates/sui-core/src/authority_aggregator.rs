@@ -1061,7 +1061,7 @@ where
                 )| {
                     if let Some(object) = object_option {
                         let system_state = object.data.try_as_move().and_then(|move_object| {
-                            bcs::from_bytes::<SuiSystemState>(move_object.contents()).ok()
+                            SuiSystemState::from_bcs_bytes(move_object.contents()).ok()
                         })?;
                         if system_state.epoch < minimal_epoch {
                             None
@@ -1725,6 +1725,22 @@ where
         &self,
         certificate: CertifiedTransaction,
     ) -> Result<CertifiedTransactionEffects, SuiError> {
+        self.process_certificate_or_partial_effects(certificate)
+            .await?
+            .map_err(
+                |partial_effects| SuiError::QuorumFailedToExecuteCertificate {
+                    errors: partial_effects.errors,
+                },
+            )
+    }
+
+    /// Like `process_certificate`, but when no effects reach a quorum, returns the effects
+    /// with the most stake behind them along with the signatures collected for them, rather
+    /// than an error. Fails only when no validator returned any effects.
+    pub async fn process_certificate_or_partial_effects(
+        &self,
+        certificate: CertifiedTransaction,
+    ) -> SuiResult<Result<CertifiedTransactionEffects, PartialTransactionEffects>> {
         struct EffectsStakeInfo {
             stake: StakeUnit,
             effects: TransactionEffects,
@@ -1825,7 +1841,7 @@ where
                                         bad_stake = state.bad_stake,
                                         "Too many bad responses from validators cert processing, validity threshold exceeded."
                                     );
-                                    return Err(SuiError::QuorumFailedToExecuteCertificate { errors: state.errors });
+                                    return Ok(ReduceOutput::End(state));
                                 }
                             }
                             _ => { unreachable!("SafeClient should have ruled out this case") }
@@ -1846,27 +1862,46 @@ where
         );
 
         // Check that one effects structure has more than 2f votes,
-        // and return it.
-        for stake_info in state.effects_map.into_values() {
-            let EffectsStakeInfo {
+        // and return it. Otherwise return the one with the most votes as partial effects.
+        let best_effects = state
+            .effects_map
+            .into_values()
+            .max_by_key(|stake_info| stake_info.stake);
+        match best_effects {
+            Some(EffectsStakeInfo {
                 stake,
                 effects,
                 signatures,
-            } = stake_info;
-            if stake >= threshold {
+            }) if stake >= threshold => {
                 debug!(
                     tx_digest = ?tx_digest,
                     good_stake = stake,
                     "Found an effect with good stake over threshold"
                 );
-                return CertifiedTransactionEffects::new(effects, signatures, &self.committee);
+                CertifiedTransactionEffects::new(effects, signatures, &self.committee).map(Ok)
+            }
+            Some(EffectsStakeInfo {
+                stake,
+                effects,
+                signatures,
+            }) => {
+                debug!(
+                    tx_digest = ?tx_digest,
+                    stake,
+                    "No effect reached the quorum threshold, returning partial effects"
+                );
+                Ok(Err(PartialTransactionEffects {
+                    effects,
+                    stake,
+                    signatures,
+                    errors: state.errors,
+                }))
             }
+            // If no validator returned effects, fail.
+            None => Err(SuiError::QuorumFailedToExecuteCertificate {
+                errors: state.errors,
+            }),
         }
-
-        // If none has, fail.
-        Err(SuiError::QuorumFailedToExecuteCertificate {
-            errors: state.errors,
-        })
     }
 
     pub async fn execute_transaction(
@@ -273,11 +273,17 @@ impl AuthorityAPI for NetworkAuthorityClient {
             .handle_committee_info_request_latency
             .start_timer();
 
-        self.client()
-            .committee_info(request)
-            .await
-            .map(tonic::Response::into_inner)
-            .map_err(Into::into)
+        match self.client().committee_info_v2(request.clone()).await {
+            Ok(response) => Ok(response.into_inner()),
+            // Authorities that predate the reference gas price only serve the legacy route.
+            Err(status) if status.code() == tonic::Code::Unimplemented => self
+                .client()
+                .committee_info(request)
+                .await
+                .map(|response| response.into_inner().into())
+                .map_err(Into::into),
+            Err(status) => Err(status.into()),
+        }
     }
 }
 
@@ -6,7 +6,7 @@ use crate::{
     authority::{AuthorityState, ReconfigConsensusMessage},
     consensus_adapter::{
         CheckpointConsensusAdapter, CheckpointSender, ConsensusAdapter, ConsensusAdapterMetrics,
-        ConsensusListener, ConsensusListenerMessage,
+        ConsensusListener, ConsensusListenerMessage, ConsensusSubmitTimes,
     },
     metrics::start_timer,
 };
@@ -99,6 +99,7 @@ impl AuthorityServer {
             state.clone_committee(),
             tx_consensus_listener,
             Duration::from_secs(20),
+            Default::default(),
             metrics,
         );
 
@@ -136,13 +137,22 @@ impl AuthorityServer {
     pub async fn spawn_with_bind_address_for_test(
         self,
         address: Multiaddr,
+    ) -> Result<AuthorityServerHandle, io::Error> {
+        self.spawn_with_server_config_for_test(address, mysten_network::config::Config::new())
+            .await
+    }
+
+    pub async fn spawn_with_server_config_for_test(
+        self,
+        address: Multiaddr,
+        server_config: mysten_network::config::Config,
     ) -> Result<AuthorityServerHandle, io::Error> {
         // Start the batching subsystem
         let _join_handle = self
             .spawn_batch_subsystem(self.min_batch_size, self.max_delay)
             .await;
 
-        let mut server = mysten_network::config::Config::new()
+        let mut server = server_config
             .server_builder()
             .add_service(ValidatorServer::new(ValidatorService {
                 state: self.state,
@@ -266,8 +276,12 @@ impl ValidatorService {
         let consensus_committee = config.genesis()?.narwhal_committee().load();
         let consensus_worker_cache = config.genesis()?.narwhal_worker_cache();
         let consensus_storage_base_path = consensus_config.db_path().to_path_buf();
-        let consensus_execution_state =
-            ConsensusHandler::new(state.clone(), tx_consensus_listener.clone());
+        let submit_times = ConsensusSubmitTimes::default();
+        let consensus_execution_state = ConsensusHandler::new(
+            state.clone(),
+            tx_consensus_listener.clone(),
+            submit_times.clone(),
+        );
         let consensus_execution_state = Arc::new(consensus_execution_state);
         let consensus_parameters = consensus_config.narwhal_config().to_owned();
         let network_keypair = config.network_key_pair.copy();
@@ -302,6 +316,7 @@ impl ValidatorService {
             state.clone_committee(),
             tx_consensus_listener.clone(),
             timeout,
+            submit_times,
             ca_metrics.clone(),
         );
 
@@ -411,7 +426,7 @@ impl ValidatorService {
 
         // 3) If the validator is already halted, we stop here, to avoid
         // sending the transaction to consensus.
-        if state.is_halted() && !certificate.signed_data.data.kind.is_system_tx() {
+        if state.is_halted() && !certificate.signed_data.data.kind.is_change_epoch_tx() {
             return Err(tonic::Status::internal(
                 SuiError::ValidatorHaltedAtEpochEnd.to_string(),
             ));
@@ -596,6 +611,20 @@ impl Validator for ValidatorService {
     async fn committee_info(
         &self,
         request: tonic::Request<CommitteeInfoRequest>,
+    ) -> Result<tonic::Response<LegacyCommitteeInfoResponse>, tonic::Status> {
+        let request = request.into_inner();
+
+        let response = self
+            .state
+            .handle_committee_info_request(&request)
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        return Ok(tonic::Response::new(response.into()));
+    }
+
+    async fn committee_info_v2(
+        &self,
+        request: tonic::Request<CommitteeInfoRequest>,
     ) -> Result<tonic::Response<CommitteeInfoResponse>, tonic::Status> {
         let request = request.into_inner();
 
@@ -34,7 +34,7 @@ use sui_types::{
 use tap::prelude::*;
 use tokio::time::Instant;
 
-use sui_types::base_types::AuthorityName;
+use sui_types::base_types::{AuthorityName, TransactionDigest};
 use sui_types::messages::CertifiedTransaction;
 use tokio::{
     sync::{
@@ -61,6 +61,12 @@ type ConsensusTransactionDigest = u64;
 type TxSequencedNotifier = oneshot::Sender<SuiResult<()>>;
 type TxSequencedNotifierClose = oneshot::Sender<()>;
 
+/// When this authority submitted each of its in-flight certificates to consensus, keyed by
+/// transaction digest. The `ConsensusAdapter` records the time locally, and the
+/// `ConsensusHandler` takes it out when the certificate is sequenced to measure the round
+/// trip. It is never part of the consensus payload, which must be identical across authorities.
+pub type ConsensusSubmitTimes = Arc<Mutex<HashMap<TransactionDigest, Instant>>>;
+
 const SEQUENCING_CERTIFICATE_LATENCY_SEC_BUCKETS: &[f64] = &[
     0.1, 0.25, 0.5, 1., 2.5, 5., 7.5, 10., 12.5, 15., 20., 25., 30., 60., 90., 120., 180., 300.,
     600.,
@@ -216,6 +222,8 @@ pub struct ConsensusAdapter {
     timeout: Duration,
     /// Number of submitted transactions still inflight at this node.
     num_inflight_transactions: AtomicU64,
+    /// Submission times of the certificates this authority sent to consensus.
+    submit_times: ConsensusSubmitTimes,
     /// A structure to register metrics
     opt_metrics: OptArcConsensusAdapterMetrics,
 }
@@ -227,6 +235,7 @@ impl ConsensusAdapter {
         committee: Committee,
         tx_consensus_listener: Sender<ConsensusListenerMessage>,
         timeout: Duration,
+        submit_times: ConsensusSubmitTimes,
         opt_metrics: OptArcConsensusAdapterMetrics,
     ) -> Self {
         let consensus_client = TransactionsClient::new(
@@ -240,6 +249,7 @@ impl ConsensusAdapter {
             tx_consensus_listener,
             timeout,
             num_inflight_transactions,
+            submit_times,
             opt_metrics,
         }
     }
@@ -293,12 +303,15 @@ impl ConsensusAdapter {
         let now = Instant::now();
         let should_submit = Self::should_submit(certificate);
         if should_submit {
+            // Keep the first submission time if a previous attempt is still being waited on.
+            self.submit_times.lock().entry(*tx_digest).or_insert(now);
             self.consensus_client
                 .clone()
                 .submit_transaction(TransactionProto { transaction: bytes })
                 .await
                 .map_err(|e| SuiError::ConsensusConnectionBroken(format!("{:?}", e)))
                 .tap_err(|r| {
+                    self.submit_times.lock().remove(tx_digest);
                     error!("Submit transaction failed with: {:?}", r);
                 })?;
             let inflight = self
@@ -341,6 +354,9 @@ impl ConsensusAdapter {
         };
 
         if should_submit {
+            // The handler takes the entry out when the certificate is sequenced; drop it here
+            // in case it never was, so the map does not grow.
+            self.submit_times.lock().remove(tx_digest);
             let inflight = self
                 .num_inflight_transactions
                 .fetch_sub(1, Ordering::SeqCst);
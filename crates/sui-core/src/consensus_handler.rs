@@ -3,32 +3,51 @@
 
 use crate::authority::authority_store_tables::ExecutionIndicesWithHash;
 use crate::authority::AuthorityState;
-use crate::consensus_adapter::ConsensusListenerMessage;
+use crate::consensus_adapter::{ConsensusListenerMessage, ConsensusSubmitTimes};
 use async_trait::async_trait;
 use narwhal_executor::{ExecutionIndices, ExecutionState};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
-use sui_types::messages::ConsensusTransaction;
+use sui_types::messages::{ConsensusTransaction, ConsensusTransactionKind};
 use tokio::sync::mpsc;
 use tracing::{debug, instrument, warn};
 
 pub struct ConsensusHandler {
     state: Arc<AuthorityState>,
     sender: mpsc::Sender<ConsensusListenerMessage>,
+    submit_times: ConsensusSubmitTimes,
     hash: Mutex<u64>,
 }
 
 impl ConsensusHandler {
-    pub fn new(state: Arc<AuthorityState>, sender: mpsc::Sender<ConsensusListenerMessage>) -> Self {
+    pub fn new(
+        state: Arc<AuthorityState>,
+        sender: mpsc::Sender<ConsensusListenerMessage>,
+        submit_times: ConsensusSubmitTimes,
+    ) -> Self {
         let hash = Mutex::new(0);
         Self {
             state,
             sender,
+            submit_times,
             hash,
         }
     }
 
+    /// Observe the round trip of a certificate this authority submitted itself; certificates
+    /// submitted by other authorities have no local submission time and are skipped.
+    fn observe_round_trip(&self, transaction: &ConsensusTransaction) {
+        if let ConsensusTransactionKind::UserTransaction(certificate) = &transaction.kind {
+            if let Some(submitted_at) = self.submit_times.lock().remove(certificate.digest()) {
+                self.state
+                    .metrics
+                    .consensus_round_trip_latency
+                    .observe(submitted_at.elapsed().as_secs_f64());
+            }
+        }
+    }
+
     fn update_hash(&self, index: ExecutionIndices, v: &[u8]) -> ExecutionIndicesWithHash {
         let mut hash_guard = self
             .hash
@@ -73,6 +92,7 @@ impl ExecutionState for ConsensusHandler {
                     return;
                 }
             };
+        self.observe_round_trip(&transaction);
         let sequenced_transaction = SequencedConsensusTransaction {
             consensus_output: consensus_output.clone(),
             consensus_index,
@@ -79,6 +79,11 @@ impl CommitteeStore {
             .1
     }
 
+    /// Every committee in the store, in epoch order.
+    pub fn get_all_committees(&self) -> Vec<Committee> {
+        self.committee_map.values().collect()
+    }
+
     fn database_is_empty(&self) -> bool {
         self.committee_map.iter().next().is_none()
     }
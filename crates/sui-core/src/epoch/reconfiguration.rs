@@ -46,6 +46,7 @@ where
         );
         let epoch = self.state.committee.load().epoch;
         info!(?epoch, "Starting epoch change");
+        self.state.set_reconfiguring(true);
         self.state.halt_validator();
         info!(?epoch, "Validator halted for epoch change");
         self.wait_for_validator_batch().await?;
@@ -161,6 +162,7 @@ where
 
         // Resume the validator to start accepting transactions for the new epoch.
         self.state.unhalt_validator();
+        self.state.set_reconfiguring(false);
         info!(?epoch, "Validator unhalted.");
 
         info!(
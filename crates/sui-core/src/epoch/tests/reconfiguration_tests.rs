@@ -78,6 +78,7 @@ async fn test_start_epoch_change() {
     // Validator should now be halted, but epoch change hasn't finished because it's waiting for
     // tickets to be drained.
     assert!(state.is_halted());
+    assert!(state.is_reconfiguring());
     assert!(!epoch_change_started.load(Ordering::SeqCst));
     assert_eq!(checkpoints.lock().next_transaction_sequence_expected(), 0);
 
@@ -192,6 +193,7 @@ async fn test_finish_epoch_change() {
         assert_eq!(latest_committee.epoch, 1);
         // Verify that validator is no longer halted.
         assert!(!active.state.is_halted());
+        assert!(!active.state.is_reconfiguring());
         let system_state = active.state.get_sui_system_state_object().await.unwrap();
         assert_eq!(system_state.epoch, 1);
         let (_, tx_digest) = active
@@ -20,22 +20,24 @@ use sui_types::error::{ExecutionError, ExecutionErrorKind};
 use sui_types::gas::GasCostSummary;
 use sui_types::gas_coin::GasCoin;
 #[cfg(test)]
-use sui_types::messages::ExecutionFailureStatus;
-#[cfg(test)]
 use sui_types::messages::InputObjects;
-use sui_types::messages::{ObjectArg, Pay};
+use sui_types::messages::{ObjectArg, Pay, MAX_EVENTS_PER_TX};
 use sui_types::object::{Data, MoveObject, Owner, OBJECT_START_VERSION};
 use sui_types::{
     base_types::{ObjectID, ObjectRef, SuiAddress, TransactionDigest, TxContext},
     event::{Event, TransferType},
     gas::{self, SuiGasStatus},
     messages::{
-        CallArg, ChangeEpoch, ExecutionStatus, MoveCall, MoveModulePublish, SingleTransactionKind,
-        TransactionData, TransactionEffects, TransferObject, TransferSui,
+        CallArg, ChangeEpoch, ConsensusCommitPrologue, ExecutionFailureStatus, ExecutionStatus,
+        MoveCall, MoveModulePublish, SingleTransactionKind, TransactionData, TransactionEffects,
+        TransactionKind, TransferObject, TransferSui,
     },
     object::Object,
     storage::{BackingPackageStore, Storage},
-    sui_system_state::{ADVANCE_EPOCH_FUNCTION_NAME, SUI_SYSTEM_MODULE_NAME},
+    sui_system_state::{
+        ADVANCE_EPOCH_FUNCTION_NAME, CONSENSUS_COMMIT_PROLOGUE_FUNCTION_NAME,
+        SUI_SYSTEM_MODULE_NAME,
+    },
     SUI_FRAMEWORK_ADDRESS, SUI_SYSTEM_STATE_OBJECT_ID,
 };
 use tracing::{debug, instrument, trace};
@@ -127,9 +129,12 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
     // we must still ensure an effect is committed and all objects versions incremented.
     let mut result = charge_gas_for_object_read(temporary_store, &mut gas_status);
     if result.is_ok() {
+        let is_batch = matches!(transaction_data.kind, TransactionKind::Batch(_));
+        let mut command_index = 0;
         // TODO: Since we require all mutable objects to not show up more than
         // once across single tx, we should be able to run them in parallel.
-        for single_tx in transaction_data.kind.into_single_transactions() {
+        for (index, single_tx) in transaction_data.kind.into_single_transactions().enumerate() {
+            command_index = index;
             result = match single_tx {
                 SingleTransactionKind::TransferObject(TransferObject {
                     recipient,
@@ -236,11 +241,49 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
                         tx_ctx,
                     )
                 }
+                SingleTransactionKind::ConsensusCommitPrologue(ConsensusCommitPrologue {
+                    round,
+                    commit_timestamp_ms,
+                }) => {
+                    let module_id =
+                        ModuleId::new(SUI_FRAMEWORK_ADDRESS, SUI_SYSTEM_MODULE_NAME.to_owned());
+                    let function = CONSENSUS_COMMIT_PROLOGUE_FUNCTION_NAME.to_owned();
+                    adapter::execute(
+                        move_vm,
+                        temporary_store,
+                        module_id,
+                        &function,
+                        vec![],
+                        vec![
+                            CallArg::Object(ObjectArg::SharedObject(SUI_SYSTEM_STATE_OBJECT_ID)),
+                            CallArg::Pure(bcs::to_bytes(&round).unwrap()),
+                            CallArg::Pure(bcs::to_bytes(&commit_timestamp_ms).unwrap()),
+                        ],
+                        &mut gas_status.create_move_gas_status(),
+                        tx_ctx,
+                    )
+                }
+                SingleTransactionKind::Upgrade(_) => {
+                    // Upgrades need a transaction version that no validator can enable yet and
+                    // are rejected by `check_transaction_input`, so a certificate for one can
+                    // never be formed.
+                    Err(ExecutionError::new_with_source(
+                        ExecutionErrorKind::InvariantViolation,
+                        "Package upgrades are not supported yet".to_string(),
+                    ))
+                }
             };
             if result.is_err() {
                 break;
             }
         }
+        if is_batch {
+            // Execution stops at the first failing command, so `command_index` is the culprit.
+            result = result.map_err(|e| e.in_command(command_index));
+        }
+        if result.is_ok() {
+            result = check_event_count(temporary_store);
+        }
         if result.is_err() {
             // Roll back the temporary store if execution failed.
             temporary_store.reset();
@@ -295,6 +338,21 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
     (cost_summary, result)
 }
 
+/// Fail execution if it emitted more than `MAX_EVENTS_PER_TX` events. This is a regular
+/// execution failure, so the certificate still produces effects: its changes are reverted
+/// and gas is charged.
+fn check_event_count<S>(temporary_store: &TemporaryStore<S>) -> Result<(), ExecutionError> {
+    let count = temporary_store.events().len();
+    if count > MAX_EVENTS_PER_TX {
+        return Err(ExecutionFailureStatus::TooManyEvents {
+            count,
+            max: MAX_EVENTS_PER_TX,
+        }
+        .into());
+    }
+    Ok(())
+}
+
 fn transfer_object<S>(
     temporary_store: &mut TemporaryStore<S>,
     mut object: Object,
@@ -749,3 +807,25 @@ fn test_pay_success_delete_all() {
     let owner_objs = store.get_written_objects_owned_by(&sender);
     assert!(owner_objs.is_empty());
 }
+
+#[test]
+fn test_check_event_count() {
+    let mut store: TemporaryStore<()> = temporary_store::empty_for_testing();
+    for _ in 0..MAX_EVENTS_PER_TX {
+        store.log_event(Event::EpochChange(0));
+    }
+    assert!(check_event_count(&store).is_ok());
+
+    store.log_event(Event::EpochChange(0));
+    assert_eq!(
+        check_event_count(&store).unwrap_err().to_execution_status(),
+        ExecutionFailureStatus::TooManyEvents {
+            count: MAX_EVENTS_PER_TX + 1,
+            max: MAX_EVENTS_PER_TX,
+        }
+    );
+
+    // Reverting a failed execution drops its events.
+    store.reset();
+    assert!(check_event_count(&store).is_ok());
+}
@@ -30,7 +30,7 @@ use sui_types::{
     base_types::*,
     coin,
     committee::Committee,
-    error::{SuiError, SuiResult},
+    error::{BatchValidationError, SuiError, SuiResult},
     fp_ensure,
     messages::*,
     object::{Object, ObjectRead},
@@ -53,9 +53,9 @@ use crate::{
 use sui_json::{resolve_move_function_args, SuiJsonCallArg, SuiJsonValue};
 use sui_json_rpc_types::{
     GetObjectDataResponse, GetRawObjectDataResponse, MoveCallParams, RPCTransactionRequestParams,
-    SuiData, SuiObject, SuiObjectInfo, SuiParsedMergeCoinResponse, SuiParsedPublishResponse,
-    SuiParsedSplitCoinResponse, SuiParsedTransactionResponse, SuiTransactionEffects,
-    SuiTransactionResponse, SuiTypeTag, TransferObjectParams,
+    SuiData, SuiObject, SuiObjectInfo, SuiObjectWithLayout, SuiParsedMergeCoinResponse,
+    SuiParsedPublishResponse, SuiParsedSplitCoinResponse, SuiParsedTransactionResponse,
+    SuiTransactionEffects, SuiTransactionResponse, SuiTypeTag, TransferObjectParams,
 };
 use sui_types::error::SuiError::ObjectLockConflict;
 
@@ -403,6 +403,13 @@ pub trait GatewayAPI {
     async fn get_object(&self, object_id: ObjectID)
         -> Result<GetObjectDataResponse, anyhow::Error>;
 
+    /// Get the object data, along with the layout of its Move struct in the given format
+    async fn get_object_with_layout(
+        &self,
+        object_id: ObjectID,
+        options: ObjectFormatOptions,
+    ) -> Result<SuiObjectWithLayout, anyhow::Error>;
+
     /// Get the object data
     async fn get_raw_object(
         &self,
@@ -1431,7 +1438,7 @@ where
         fp_ensure!(
             !single_transaction_params.is_empty(),
             SuiError::InvalidBatchTransaction {
-                error: "Batch Transaction cannot be empty".to_owned(),
+                error: BatchValidationError::Empty,
             }
             .into()
         );
@@ -1645,6 +1652,27 @@ where
         Ok(result.try_into()?)
     }
 
+    async fn get_object_with_layout(
+        &self,
+        object_id: ObjectID,
+        options: ObjectFormatOptions,
+    ) -> Result<SuiObjectWithLayout, anyhow::Error> {
+        let result = self.download_object_from_authorities(object_id).await?;
+        let layout = match &result {
+            ObjectRead::Exists(_, object, _) => {
+                if let Data::Move(move_object) = &object.data {
+                    self.load_object_transitive_deps(&move_object.type_).await?;
+                }
+                object.get_layout(options, &self.module_cache)?
+            }
+            _ => None,
+        };
+        Ok(SuiObjectWithLayout {
+            object: result.try_into()?,
+            layout,
+        })
+    }
+
     async fn get_raw_object(
         &self,
         object_id: ObjectID,
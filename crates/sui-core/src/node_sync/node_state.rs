@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    authority::AuthorityState, authority_active::gossip::GossipMetrics,
-    authority_active::ActiveAuthority, authority_aggregator::AuthorityAggregator,
-    authority_client::AuthorityAPI,
+    authority::AuthorityState, authority_active::execution_driver::log_failed_execution,
+    authority_active::gossip::GossipMetrics, authority_active::ActiveAuthority,
+    authority_aggregator::AuthorityAggregator, authority_client::AuthorityAPI,
 };
 
 use tokio_stream::{Stream, StreamExt};
@@ -584,7 +584,12 @@ where
             self.state().handle_certificate(&cert).await
         };
         match result {
-            Ok(_) => Ok(SyncStatus::CertExecuted),
+            Ok(response) => {
+                if let Some(effects) = &response.signed_effects {
+                    log_failed_execution(&cert, &effects.effects);
+                }
+                Ok(SyncStatus::CertExecuted)
+            }
             Err(SuiError::ObjectNotFound { .. }) | Err(SuiError::ObjectErrors { .. }) => {
                 debug!(?digest, "cert execution failed due to missing parents");
 
@@ -599,13 +604,16 @@ where
 
                 // Parents have been executed, so this should now succeed.
                 debug!(?digest, "parents executed, re-attempting cert");
-                if bypass_validator_halt {
+                let response = if bypass_validator_halt {
                     self.state()
                         .handle_certificate_bypass_validator_halt(&cert)
                         .await
                 } else {
                     self.state().handle_certificate(&cert).await
                 }?;
+                if let Some(effects) = &response.signed_effects {
+                    log_failed_execution(&cert, &effects.effects);
+                }
                 Ok(SyncStatus::CertExecuted)
             }
             Err(e) => Err(e),
@@ -125,6 +125,16 @@ where
 
                 (&self.metrics.total_ok_responses_wait_for_effects_cert, res)
             }
+            QuorumDriverRequestType::WaitForEffectsCertOrPartialEffects => {
+                self.metrics.total_requests_wait_for_effects_cert.inc();
+                let _timer = self.metrics.latency_sec_wait_for_effects_cert.start_timer();
+
+                let res = self
+                    .execute_transaction_wait_for_effects_cert_or_partial_effects(transaction)
+                    .await;
+
+                (&self.metrics.total_ok_responses_wait_for_effects_cert, res)
+            }
         };
         if result.is_ok() {
             ok_metric.inc()
@@ -177,6 +187,33 @@ where
         Ok(QuorumDriverResponse::EffectsCert(Box::new(response)))
     }
 
+    async fn execute_transaction_wait_for_effects_cert_or_partial_effects(
+        &self,
+        transaction: Transaction,
+    ) -> SuiResult<QuorumDriverResponse> {
+        let certificate = self
+            .process_transaction(transaction)
+            .instrument(tracing::debug_span!("process_tx"))
+            .await?;
+        let result = self
+            .validators
+            .load()
+            .process_certificate_or_partial_effects(certificate.clone())
+            .instrument(tracing::debug_span!("process_cert"))
+            .await?;
+        match result {
+            Ok(effects) => {
+                let response = (certificate, effects);
+                self.notify_effects_subscribers(&response);
+                Ok(QuorumDriverResponse::EffectsCert(Box::new(response)))
+            }
+            Err(partial_effects) => Ok(QuorumDriverResponse::PartialEffects(Box::new((
+                certificate,
+                partial_effects,
+            )))),
+        }
+    }
+
     pub async fn process_transaction(
         &self,
         transaction: Transaction,
@@ -199,12 +236,19 @@ where
             .instrument(tracing::debug_span!("process_cert"))
             .await?;
         let response = (certificate, effects);
+        self.notify_effects_subscribers(&response);
+        Ok(response)
+    }
+
+    fn notify_effects_subscribers(
+        &self,
+        response: &(CertifiedTransaction, CertifiedTransactionEffects),
+    ) {
         // An error to send the result to subscribers should not block returning the result.
         if let Err(err) = self.effects_subscribe_sender.send(response.clone()) {
             // TODO: We could potentially retry sending if we want.
             debug!("No subscriber found for effects: {}", err);
         }
-        Ok(response)
     }
 
     pub async fn update_validators(
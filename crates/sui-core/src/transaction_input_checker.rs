@@ -3,18 +3,18 @@
 
 use crate::authority::SuiDataStore;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use sui_types::base_types::ObjectRef;
 use sui_types::messages::TransactionKind;
 use sui_types::{
-    base_types::{SequenceNumber, SuiAddress},
-    error::{SuiError, SuiResult},
+    base_types::{ObjectID, SequenceNumber, SuiAddress},
+    error::{BatchValidationError, SuiError, SuiResult},
     fp_ensure,
     gas::{self, SuiGasStatus},
     messages::{
-        CertifiedTransaction, InputObjectKind, InputObjects, SingleTransactionKind,
-        TransactionData, TransactionEnvelope,
+        CallArg, CertifiedTransaction, InputObjectKind, InputObjects, ObjectArg,
+        SingleTransactionKind, TransactionData, TransactionEnvelope, MAX_DEPENDENCIES,
     },
     object::{Object, Owner},
 };
@@ -54,8 +54,31 @@ where
     S: Eq + Debug + Serialize + for<'de> Deserialize<'de>,
 {
     transaction.signed_data.data.kind.validity_check()?;
+    // Package upgrades can be expressed but not yet executed; refuse to sign them rather
+    // than charging gas for a transaction that is bound to fail.
+    fp_ensure!(
+        !transaction
+            .signed_data
+            .data
+            .kind
+            .single_transactions()
+            .any(|s| matches!(s, SingleTransactionKind::Upgrade(_))),
+        SuiError::UnsupportedFeatureError {
+            error: "Package upgrades are not supported yet".to_string(),
+        }
+    );
     let gas_status = get_gas_status(store, transaction).await?;
-    let input_objects = transaction.signed_data.data.input_objects()?;
+    let input_objects = store.cached_input_objects(transaction)?;
+    // Each input object contributes at most one dependency to the effects, so bounding the
+    // inputs before signing guarantees that no certificate ever exceeds `MAX_DEPENDENCIES`
+    // at execution, whatever versions consensus assigns to its shared objects.
+    fp_ensure!(
+        input_objects.len() <= MAX_DEPENDENCIES,
+        SuiError::TooManyTransactionDependencies {
+            count: input_objects.len(),
+            max: MAX_DEPENDENCIES,
+        }
+    );
     let objects = store.get_input_objects(&input_objects)?;
     let input_objects =
         check_objects(&transaction.signed_data.data, input_objects, objects).await?;
@@ -70,7 +93,7 @@ where
     S: Eq + Debug + Serialize + for<'de> Deserialize<'de>,
 {
     let gas_status = get_gas_status(store, cert).await?;
-    let input_objects = cert.signed_data.data.input_objects()?;
+    let input_objects = store.cached_input_objects(cert)?;
 
     let tx_data = &cert.signed_data.data;
     let objects = if tx_data.kind.is_change_epoch_tx() {
@@ -153,13 +176,18 @@ async fn check_objects(
     // TODO: We should be able to allow the same shared object to show up
     // in more than one SingleTransactionKind. We need to ensure that their
     // version number only increases once at the end of the Batch execution.
+    // Read-only shared objects are never written, so they may be read by more than one
+    // SingleTransactionKind and cannot authenticate other objects.
     let mut owned_object_authenticators: HashSet<SuiAddress> = HashSet::new();
-    for object in objects.iter() {
+    for (object_kind, object) in input_objects.iter().zip(objects.iter()) {
+        if matches!(object_kind, InputObjectKind::ReadonlySharedMoveObject(_)) {
+            continue;
+        }
         if !object.is_immutable() {
             fp_ensure!(
                 owned_object_authenticators.insert(object.id().into()),
                 SuiError::InvalidBatchTransaction {
-                    error: format!("Mutable object {} cannot appear in more than one single transactions in a batch", object.id()),
+                    error: BatchValidationError::DuplicateMutableObject(object.id()),
                 }
             );
         }
@@ -179,11 +207,55 @@ async fn check_objects(
             }
         })
         .collect();
+    // Each received object, with the other objects passed to its call, one of which must own it.
+    let mut receiving_objects: HashMap<ObjectID, HashSet<ObjectID>> = HashMap::new();
+    for call in transaction
+        .kind
+        .single_transactions()
+        .filter_map(|s| s.move_call())
+    {
+        let mut received = Vec::new();
+        let mut parents = HashSet::new();
+        for obj_arg in call.arguments.iter().flat_map(|arg| match arg {
+            CallArg::Object(obj_arg) => std::slice::from_ref(obj_arg),
+            CallArg::ObjVec(obj_args) => obj_args.as_slice(),
+            CallArg::Pure(_) => &[],
+        }) {
+            match obj_arg {
+                ObjectArg::Receiving((id, _, _)) => received.push(*id),
+                ObjectArg::ImmOrOwnedObject((id, _, _))
+                | ObjectArg::SharedObject(id)
+                | ObjectArg::ReadonlySharedObject(id) => {
+                    parents.insert(*id);
+                }
+            }
+        }
+        for id in received {
+            receiving_objects.insert(id, parents.clone());
+        }
+    }
 
     for (object_kind, object) in input_objects.into_iter().zip(objects) {
         if transfer_object_ids.contains(&object.id()) {
             object.ensure_public_transfer_eligible()?;
         }
+        // A received object must be owned by one of the other objects passed to its call.
+        if let Some(parents) = receiving_objects.get(&object.id()) {
+            let error = match object.owner {
+                Owner::ObjectOwner(owner) if parents.contains(&owner.into()) => None,
+                Owner::ObjectOwner(owner) => Some(SuiError::ReceivingObjectOwnerMismatch {
+                    object_id: object.id(),
+                    owner: owner.into(),
+                }),
+                _ => Some(SuiError::ReceivingObjectNotObjectOwned {
+                    object_id: object.id(),
+                }),
+            };
+            if let Some(error) = error {
+                errors.push(error);
+                continue;
+            }
+        }
         // Check if the object contents match the type of lock we need for
         // this object.
         match check_one_object(
@@ -292,7 +364,7 @@ fn check_one_object(
                 }
             };
         }
-        InputObjectKind::SharedMoveObject(..) => {
+        InputObjectKind::SharedMoveObject(..) | InputObjectKind::ReadonlySharedMoveObject(..) => {
             fp_ensure!(
                 object.version() < SequenceNumber::MAX,
                 SuiError::InvalidSequenceNumber
@@ -109,6 +109,9 @@ where
             | ExecuteTransactionRequestType::WaitForLocalExecution => {
                 QuorumDriverRequestType::WaitForEffectsCert
             }
+            ExecuteTransactionRequestType::BestEffortWithPartialEffects => {
+                QuorumDriverRequestType::WaitForEffectsCertOrPartialEffects
+            }
         };
         let execution_result = self
             .quorum_driver
@@ -157,6 +160,12 @@ where
                     )))),
                 }
             }
+            QuorumDriverResponse::PartialEffects(result) => {
+                let (_, partial_effects) = *result;
+                Ok(ExecuteTransactionResponse::PartialEffects(Box::new(
+                    partial_effects.effects,
+                )))
+            }
         }
     }
 
@@ -178,6 +187,17 @@ where
         //      (for one extra time)
         // 3. at the end of day, the tx will be executed at most once per lock guard.
         let tx_digest = tx_cert.digest();
+        if !tx_cert.matches_effects(effects_cert) {
+            error!(
+                ?tx_digest,
+                effects_tx_digest = ?effects_cert.effects.transaction_digest,
+                "Orchestrator received effects that do not belong to the certificate."
+            );
+            return Err(SuiError::CertificateEffectsMismatch {
+                certificate_digest: *tx_digest,
+                effects_digest: effects_cert.effects.transaction_digest,
+            });
+        }
         if validator_state.is_tx_already_executed(tx_digest)? {
             return Ok(());
         }
@@ -346,7 +366,9 @@ where
                     &self.metrics.good_response_wait_for_tx_cert,
                 )
             }
-            ExecuteTransactionRequestType::WaitForEffectsCert => {
+            // Best effort requests wait for effects certificates like WaitForEffectsCert.
+            ExecuteTransactionRequestType::WaitForEffectsCert
+            | ExecuteTransactionRequestType::BestEffortWithPartialEffects => {
                 self.metrics.total_req_received_wait_for_effects_cert.inc();
                 (
                     &self.metrics.req_in_flight_wait_for_effects_cert,
@@ -900,6 +900,53 @@ async fn test_process_transaction_fault_fail() {
     .is_err());
 }
 
+#[sim_test]
+async fn test_process_certificate_returns_partial_effects() {
+    let (addr1, key1): (_, AccountKeyPair) = get_key_pair();
+    let (addr2, _): (_, AccountKeyPair) = get_key_pair();
+    let gas_object1 = Object::with_owner_for_testing(addr1);
+    let gas_object2 = Object::with_owner_for_testing(addr1);
+    let mut authorities = init_local_authorities(4, vec![gas_object1.clone(), gas_object2.clone()])
+        .await
+        .0;
+
+    let tx = transfer_coin_transaction(
+        addr1,
+        &key1,
+        addr2,
+        gas_object1.compute_object_reference(),
+        gas_object2.compute_object_reference(),
+    );
+    let cert = authorities.process_transaction(tx).await.unwrap();
+
+    // Two of the four validators fail to execute the certificate, so the effects returned by
+    // the other two cannot reach a quorum.
+    let fail_before_process_certificate_config = LocalAuthorityClientFaultConfig {
+        fail_before_handle_confirmation: true,
+        ..Default::default()
+    };
+    for index in [0, 1] {
+        get_local_client(&mut authorities, index).fault_config =
+            fail_before_process_certificate_config;
+    }
+
+    let partial_effects = authorities
+        .process_certificate_or_partial_effects(cert.clone())
+        .await
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(partial_effects.effects.transaction_digest, *cert.digest());
+    assert_eq!(partial_effects.signatures.len(), 2);
+    assert_eq!(partial_effects.errors.len(), 2);
+    assert!(partial_effects.stake < authorities.committee.quorum_threshold());
+
+    // Without partial effects, the same outcome is an error.
+    assert!(matches!(
+        authorities.process_certificate(cert).await,
+        Err(SuiError::QuorumFailedToExecuteCertificate { .. })
+    ));
+}
+
 #[derive(Clone)]
 struct MockAuthorityApi {
     delay: Duration,
@@ -1318,6 +1365,7 @@ async fn test_get_committee_info() {
     let good_result = Ok(CommitteeInfoResponse {
         epoch: 0,
         committee_info: Some(authorities_vec.clone()),
+        reference_gas_price: None,
     });
     for client in clients.values_mut() {
         client.set_handle_committee_info_request_result(good_result.clone());
@@ -1380,6 +1428,7 @@ async fn test_get_committee_info() {
     let empty_result = Ok(CommitteeInfoResponse {
         epoch: 0,
         committee_info: None,
+        reference_gas_price: None,
     });
     let mut i = 0;
     for client in clone_clients.values_mut() {
@@ -31,6 +31,7 @@ use sui_types::{
     crypto::{get_key_pair, Signature},
     crypto::{AccountKeyPair, AuthorityKeyPair, KeypairTraits},
     messages::Transaction,
+    messages_checkpoint::CheckpointContents,
     object::{Owner, GAS_VALUE_FOR_TESTING, OBJECT_START_VERSION},
     sui_system_state::SuiSystemState,
     SUI_SYSTEM_STATE_OBJECT_ID,
@@ -182,6 +183,32 @@ async fn test_dry_run_transaction() {
     assert_eq!(shared_object_version, SequenceNumber::MIN);
 }
 
+#[tokio::test]
+async fn test_handle_transaction_too_many_dependencies() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+    let authority_state = init_state_with_objects(vec![gas_object.clone()]).await;
+
+    // With the gas object, one input more than the bound: refused before any coin is loaded.
+    let coins = (0..MAX_DEPENDENCIES)
+        .map(|_| test_utils::messages::random_object_ref())
+        .collect();
+    let tx_data = TransactionData::new_pay(
+        sender,
+        coins,
+        vec![dbg_addr(2)],
+        vec![1],
+        gas_object.compute_object_reference(),
+        MAX_GAS,
+    );
+    let transaction = to_sender_signed_transaction(tx_data, &sender_key);
+    assert!(matches!(
+        authority_state.handle_transaction(transaction).await,
+        Err(SuiError::TooManyTransactionDependencies { count, max })
+            if count == MAX_DEPENDENCIES + 1 && max == MAX_DEPENDENCIES
+    ));
+}
+
 #[tokio::test]
 async fn test_handle_transfer_transaction_bad_signature() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -777,6 +804,240 @@ async fn test_handle_move_transaction() {
     assert_eq!(created_obj.version(), OBJECT_START_VERSION);
 }
 
+#[tokio::test]
+async fn test_move_call_by_package_id() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_payment_object_id = ObjectID::random();
+    let (authority_state, pkg_ref) =
+        init_state_with_ids_and_object_basics(vec![(sender, gas_payment_object_id)]).await;
+    let gas_object_ref = authority_state
+        .get_object(&gas_payment_object_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let make_call = |package| {
+        TransactionData::new_move_call_by_package_id(
+            sender,
+            package,
+            &authority_state.database,
+            ident_str!("object_basics").to_owned(),
+            ident_str!("create").to_owned(),
+            vec![],
+            gas_object_ref,
+            vec![
+                CallArg::Pure(bcs::to_bytes(&(16_u64)).unwrap()),
+                CallArg::Pure(bcs::to_bytes(&sender).unwrap()),
+            ],
+            MAX_GAS,
+        )
+    };
+
+    // The package reference is looked up in the store.
+    let data = make_call(pkg_ref.0).unwrap();
+    assert_eq!(data.move_calls()[0].package, pkg_ref);
+    let transaction = to_sender_signed_transaction(data, &sender_key);
+    let (_, input_objects) =
+        transaction_input_checker::check_transaction_input(&authority_state.database, &transaction)
+            .await
+            .unwrap();
+    assert_eq!(input_objects.resolved_package_refs(), vec![pkg_ref]);
+
+    let effects = send_and_confirm_transaction(&authority_state, transaction)
+        .await
+        .unwrap()
+        .signed_effects
+        .unwrap()
+        .effects;
+    assert!(effects.status.is_ok());
+    assert_eq!(effects.created.len(), 1);
+
+    // An unknown package ID is reported as a missing dependent package.
+    let missing = ObjectID::random();
+    assert_eq!(
+        make_call(missing).unwrap_err(),
+        SuiError::DependentPackageNotFound {
+            package_id: missing
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_receiving_object_arg_ownership() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+    let parent = Object::with_owner_for_testing(sender);
+    let child = Object::with_object_owner_for_testing(ObjectID::random(), parent.id());
+    let authority_state =
+        init_state_with_objects(vec![gas_object.clone(), parent.clone(), child.clone()]).await;
+    let package_object_ref = authority_state.get_framework_object_ref().await.unwrap();
+    let check = |arguments| {
+        let data = TransactionData::new_move_call(
+            sender,
+            package_object_ref,
+            ident_str!("coin").to_owned(),
+            ident_str!("join").to_owned(),
+            vec![],
+            gas_object.compute_object_reference(),
+            arguments,
+            MAX_GAS,
+        );
+        let transaction = to_sender_signed_transaction(data, &sender_key);
+        let database = authority_state.database.clone();
+        async move {
+            transaction_input_checker::check_transaction_input(&database, &transaction)
+                .await
+                .map(|_| ())
+        }
+    };
+
+    // An object received alongside its parent is accepted.
+    check(vec![
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(
+            parent.compute_object_reference(),
+        )),
+        CallArg::Object(ObjectArg::Receiving(child.compute_object_reference())),
+    ])
+    .await
+    .unwrap();
+
+    // Its parent must be passed to the same call.
+    assert_eq!(
+        check(vec![CallArg::Object(ObjectArg::Receiving(
+            child.compute_object_reference()
+        ))])
+        .await
+        .unwrap_err(),
+        SuiError::ObjectErrors {
+            errors: vec![SuiError::ReceivingObjectOwnerMismatch {
+                object_id: child.id(),
+                owner: parent.id(),
+            }]
+        }
+    );
+
+    // An object owned by an address cannot be received.
+    assert_eq!(
+        check(vec![CallArg::Object(ObjectArg::Receiving(
+            parent.compute_object_reference()
+        ))])
+        .await
+        .unwrap_err(),
+        SuiError::ObjectErrors {
+            errors: vec![SuiError::ReceivingObjectNotObjectOwned {
+                object_id: parent.id()
+            }]
+        }
+    );
+
+    // The validator only signs receiving calls once their transaction version is enabled.
+    let data = TransactionData::new_move_call(
+        sender,
+        package_object_ref,
+        ident_str!("coin").to_owned(),
+        ident_str!("join").to_owned(),
+        vec![],
+        gas_object.compute_object_reference(),
+        vec![
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(
+                parent.compute_object_reference(),
+            )),
+            CallArg::Object(ObjectArg::Receiving(child.compute_object_reference())),
+        ],
+        MAX_GAS,
+    );
+    assert_eq!(
+        authority_state
+            .handle_transaction(to_sender_signed_transaction(data, &sender_key))
+            .await
+            .unwrap_err(),
+        SuiError::UnsupportedTransactionVersion {
+            required: 2,
+            enabled: BASE_TRANSACTION_VERSION,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_upgrade_transaction_not_supported() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+    let ticket = Object::with_owner_for_testing(sender);
+    let authority_state = init_state_with_objects(vec![gas_object.clone(), ticket.clone()]).await;
+    let package_object_ref = authority_state.get_framework_object_ref().await.unwrap();
+    let data = TransactionData::new_upgrade(
+        sender,
+        gas_object.compute_object_reference(),
+        package_object_ref.0,
+        vec![],
+        ticket.compute_object_reference(),
+        MAX_GAS,
+    );
+    let transaction = to_sender_signed_transaction(data, &sender_key);
+    assert!(matches!(
+        transaction_input_checker::check_transaction_input(&authority_state.database, &transaction)
+            .await
+            .unwrap_err(),
+        SuiError::UnsupportedFeatureError { .. }
+    ));
+
+    // Even with every supported version enabled, the validator refuses to sign it.
+    authority_state
+        .enable_transaction_version(MAX_TRANSACTION_VERSION)
+        .unwrap();
+    assert_eq!(
+        authority_state
+            .handle_transaction(transaction)
+            .await
+            .unwrap_err(),
+        SuiError::UnsupportedTransactionVersion {
+            required: MAX_TRANSACTION_VERSION + 1,
+            enabled: MAX_TRANSACTION_VERSION,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_cached_input_objects() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+    let object = Object::with_owner_for_testing(sender);
+    let authority_state = init_state_with_objects(vec![gas_object.clone(), object.clone()]).await;
+    let transfer = |gas_budget| {
+        to_sender_signed_transaction(
+            TransactionData::new_transfer(
+                dbg_addr(2),
+                object.compute_object_reference(),
+                sender,
+                gas_object.compute_object_reference(),
+                gas_budget,
+            ),
+            &sender_key,
+        )
+    };
+    let transaction = transfer(MAX_GAS);
+    let expected = transaction.signed_data.data.input_objects().unwrap();
+
+    // Both the miss and the hit return the same objects as the uncached call.
+    for _ in 0..2 {
+        assert_eq!(
+            authority_state.cached_input_objects(&transaction).unwrap(),
+            expected
+        );
+    }
+
+    // The cache never grows past its capacity.
+    for gas_budget in 0..(INPUT_OBJECTS_CACHE_SIZE + 10) as u64 {
+        authority_state
+            .cached_input_objects(&transfer(gas_budget))
+            .unwrap();
+    }
+    assert_eq!(
+        authority_state.database.input_objects_cache.lock().len(),
+        INPUT_OBJECTS_CACHE_SIZE
+    );
+}
+
 #[tokio::test]
 async fn test_handle_transfer_transaction_double_spend() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -1770,6 +2031,77 @@ async fn test_change_epoch_transaction() {
     assert_eq!(sui_system_object.epoch, 1);
 }
 
+#[tokio::test]
+async fn test_consensus_commit_prologue_transaction() {
+    let authority_state = init_state().await;
+    let kind = TransactionKind::Single(SingleTransactionKind::ConsensusCommitPrologue(
+        ConsensusCommitPrologue {
+            round: 3,
+            commit_timestamp_ms: 42,
+        },
+    ));
+
+    // The prologue cannot be created until its transaction version is enabled.
+    assert_eq!(kind.required_version(), MAX_TRANSACTION_VERSION);
+    assert_eq!(
+        authority_state.check_transaction_version(&kind),
+        Err(SuiError::UnsupportedTransactionVersion {
+            required: MAX_TRANSACTION_VERSION,
+            enabled: BASE_TRANSACTION_VERSION,
+        })
+    );
+    assert!(authority_state
+        .enable_transaction_version(MAX_TRANSACTION_VERSION + 1)
+        .is_err());
+    authority_state
+        .enable_transaction_version(MAX_TRANSACTION_VERSION)
+        .unwrap();
+    assert!(authority_state.check_transaction_version(&kind).is_ok());
+
+    // Like `ChangeEpoch`, the prologue is sent by 0x0 and does not pay for gas.
+    let data = TransactionData::new(
+        kind,
+        SuiAddress::default(),
+        (ObjectID::ZERO, SequenceNumber::default(), ObjectDigest::MIN),
+        0,
+    );
+    let (_, key): (_, AccountKeyPair) = get_key_pair();
+    let transaction = to_sender_signed_transaction(data, &key);
+    let signed_tx = SignedTransaction::new(
+        0,
+        transaction.clone(),
+        authority_state.name,
+        &*authority_state.secret,
+    );
+    let committee = authority_state.committee.load();
+    let certificate = SignatureAggregator::new_unsafe(transaction, &committee)
+        .append(
+            signed_tx.auth_sign_info.authority,
+            signed_tx.auth_sign_info.signature,
+        )
+        .unwrap()
+        .unwrap();
+
+    // The prologue writes the shared system state, so unlike `ChangeEpoch` it must be
+    // sequenced before it can execute.
+    assert_eq!(
+        authority_state
+            .handle_certificate(&certificate)
+            .await
+            .unwrap_err(),
+        SuiError::SharedObjectLockNotSetError
+    );
+    send_consensus(&authority_state, &certificate).await;
+    let result = authority_state
+        .handle_certificate(&certificate)
+        .await
+        .unwrap();
+    assert!(result.signed_effects.unwrap().effects.status.is_ok());
+    let sui_system_object = authority_state.get_sui_system_state_object().await.unwrap();
+    assert_eq!(sui_system_object.consensus_round, 3);
+    assert_eq!(sui_system_object.consensus_commit_timestamp_ms, 42);
+}
+
 #[tokio::test]
 async fn test_transfer_sui_no_amount() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -1871,6 +2203,73 @@ async fn test_transfer_sui_with_amount() {
     );
 }
 
+#[tokio::test]
+async fn test_is_reconfiguring() {
+    let authority_state = init_state().await;
+    assert!(!authority_state.is_reconfiguring());
+
+    authority_state.set_reconfiguring(true);
+    assert!(authority_state.is_reconfiguring());
+
+    authority_state.set_reconfiguring(false);
+    assert!(!authority_state.is_reconfiguring());
+}
+
+#[tokio::test]
+async fn test_execution_failure_notifications() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+    let authority_state = init_state_with_objects(vec![gas_object.clone()]).await;
+    let mut failures = authority_state.subscribe_to_execution_failures().unwrap();
+
+    // Transferring more than the gas coin holds fails during execution.
+    let tx_data = TransactionData::new_transfer_sui(
+        dbg_addr(2),
+        sender,
+        Some(u64::MAX),
+        gas_object.compute_object_reference(),
+        MAX_GAS,
+    );
+    let transaction = to_sender_signed_transaction(tx_data, &sender_key);
+    let digest = *transaction.digest();
+    let effects = send_and_confirm_transaction(&authority_state, transaction)
+        .await
+        .unwrap()
+        .signed_effects
+        .unwrap()
+        .effects;
+    assert!(effects.status.is_err());
+
+    let (failed_digest, error) = failures.try_recv().unwrap();
+    assert_eq!(failed_digest, digest);
+    assert_eq!(Some(&error), effects.status.err());
+    // Nothing else failed.
+    assert!(failures.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_committee_info_reference_gas_price() {
+    let authority_state = init_state().await;
+    let system_state = authority_state.get_sui_system_state_object().await.unwrap();
+
+    let response = authority_state
+        .handle_committee_info_request(&CommitteeInfoRequest { epoch: None })
+        .unwrap();
+    assert_eq!(
+        response.reference_gas_price,
+        Some(system_state.reference_gas_price)
+    );
+
+    // Unavailable for any epoch other than the current one.
+    let response = authority_state
+        .handle_committee_info_request(&CommitteeInfoRequest {
+            epoch: Some(system_state.epoch + 1),
+        })
+        .unwrap();
+    assert_eq!(response.committee_info, None);
+    assert_eq!(response.reference_gas_price, None);
+}
+
 #[tokio::test]
 async fn test_store_revert_state_update() {
     // This test checks the correctness of revert_state_update in SuiDataStore.
@@ -1939,6 +2338,101 @@ async fn test_store_revert_state_update() {
     assert!(authority_state.database.get_effects(&tx_digest).is_err());
 }
 
+#[tokio::test]
+async fn test_get_effects_since() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let object_ids = [ObjectID::random(), ObjectID::random()];
+    let gas_object_ids = [ObjectID::random(), ObjectID::random()];
+    let authority_state = init_state_with_ids(
+        object_ids
+            .iter()
+            .chain(gas_object_ids.iter())
+            .map(|id| (sender, *id)),
+    )
+    .await;
+
+    let mut executed = Vec::new();
+    for (object_id, gas_object_id) in object_ids.iter().zip(gas_object_ids.iter()) {
+        let object = authority_state
+            .get_object(object_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let gas_object = authority_state
+            .get_object(gas_object_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let transaction = init_transfer_transaction(
+            sender,
+            &sender_key,
+            recipient,
+            object.compute_object_reference(),
+            gas_object.compute_object_reference(),
+        );
+        let effects = send_and_confirm_transaction(&authority_state, transaction)
+            .await
+            .unwrap()
+            .signed_effects
+            .unwrap()
+            .effects;
+        executed.push(ExecutionDigests::new(
+            effects.transaction_digest,
+            effects.digest(),
+        ));
+    }
+
+    // Checkpoints 0 and 1 hold one executed transaction each, checkpoint 2 holds one whose
+    // effects this authority has not stored.
+    let contents = [
+        vec![executed[0]],
+        vec![executed[1]],
+        vec![ExecutionDigests::random()],
+    ];
+    {
+        let checkpoints = authority_state.checkpoints.lock();
+        for (seq, digests) in contents.into_iter().enumerate() {
+            checkpoints
+                .tables
+                .checkpoint_contents
+                .insert(
+                    &(seq as CheckpointSequenceNumber),
+                    &CheckpointContents::new_with_causally_ordered_transactions(digests),
+                )
+                .unwrap();
+        }
+    }
+    let page_digests = |(effects, next): (Vec<SignedTransactionEffects>, _)| {
+        let digests: Vec<_> = effects
+            .into_iter()
+            .map(|effects| effects.effects.transaction_digest)
+            .collect();
+        (digests, next)
+    };
+
+    // Pages hold whole checkpoints up to the limit.
+    assert_eq!(
+        page_digests(authority_state.get_effects_since(0, 1).unwrap()),
+        (vec![executed[0].transaction], 1)
+    );
+    // The page stops at the last checkpoint whose effects are all stored locally.
+    assert_eq!(
+        page_digests(authority_state.get_effects_since(0, 10).unwrap()),
+        (vec![executed[0].transaction, executed[1].transaction], 2)
+    );
+    // An incomplete checkpoint is not skipped: the cursor stays on it until it is complete.
+    assert_eq!(
+        page_digests(authority_state.get_effects_since(2, 10).unwrap()),
+        (vec![], 2)
+    );
+    // Past the last checkpoint the cursor is the requested checkpoint, not a dead end.
+    assert_eq!(
+        page_digests(authority_state.get_effects_since(3, 10).unwrap()),
+        (vec![], 3)
+    );
+}
+
 // helpers
 
 #[cfg(test)]
@@ -2322,6 +2816,51 @@ async fn shared_object() {
     assert_eq!(shared_object_version, SequenceNumber::from(2));
 }
 
+#[tokio::test]
+async fn test_readonly_shared_object_versions() {
+    let (sender, keypair): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+
+    let shared_object_id = ObjectID::random();
+    let shared_object = {
+        use sui_types::gas_coin::GasCoin;
+        use sui_types::object::MoveObject;
+
+        let content = GasCoin::new(shared_object_id, 10);
+        let obj = MoveObject::new_gas_coin(OBJECT_START_VERSION, content.to_bcs_bytes());
+        Object::new_move(obj, Owner::Shared, TransactionDigest::genesis())
+    };
+
+    let authority = init_state_with_objects(vec![gas_object.clone(), shared_object]).await;
+    let package_object_ref = authority.get_framework_object_ref().await.unwrap();
+    let make_certificate = |gas_budget| {
+        let data = TransactionData::new_move_call(
+            sender,
+            package_object_ref,
+            ident_str!("coin").to_owned(),
+            ident_str!("value").to_owned(),
+            vec![],
+            gas_object.compute_object_reference(),
+            vec![CallArg::Object(ObjectArg::ReadonlySharedObject(
+                shared_object_id,
+            ))],
+            gas_budget,
+        );
+        init_certified_transaction(to_sender_signed_transaction(data, &keypair), &authority)
+    };
+
+    // Readers do not bump the version of the shared object, so they are all sequenced at
+    // the same version.
+    for certificate in [make_certificate(MAX_GAS), make_certificate(MAX_GAS - 1)] {
+        send_consensus(&authority, &certificate).await;
+        let version = authority
+            .db()
+            .get_assigned_object_versions(certificate.digest(), [shared_object_id].iter())
+            .unwrap()[0];
+        assert_eq!(version, Some(OBJECT_START_VERSION));
+    }
+}
+
 #[tokio::test]
 async fn test_consensus_message_processed() {
     telemetry_subscribers::init_for_testing();
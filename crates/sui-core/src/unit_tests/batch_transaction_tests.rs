@@ -9,12 +9,14 @@ use crate::{
 use super::*;
 use bcs;
 
-use authority_tests::{init_state_with_ids, send_and_confirm_transaction};
+use authority_tests::{init_state_with_ids, init_state_with_objects, send_and_confirm_transaction};
 use move_binary_format::file_format;
 use move_core_types::{account_address::AccountAddress, ident_str};
 use sui_types::{
     crypto::{get_key_pair, AccountKeyPair},
-    object::Owner,
+    error::BatchValidationError,
+    gas_coin::{GasCoin, GAS},
+    object::{MoveObject, Owner, OBJECT_START_VERSION},
 };
 
 #[tokio::test]
@@ -132,12 +134,62 @@ async fn test_batch_transaction_last_one_fail() -> anyhow::Result<()> {
 
     let response = send_and_confirm_transaction(&authority_state, tx).await?;
     let effects = response.signed_effects.unwrap().effects;
-    assert!(effects.status.is_err());
+    assert!(matches!(
+        effects.status,
+        ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InCommand { index: N, .. }
+        }
+    ));
     assert_eq!((effects.created.len(), effects.mutated.len()), (0, N + 1));
 
     Ok(())
 }
 
+#[tokio::test]
+async fn test_batch_reads_shared_object_twice() -> anyhow::Result<()> {
+    // Test that a batch transaction may read the same shared object in more than one call,
+    // while using it mutably in more than one call is still rejected.
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = Object::with_owner_for_testing(sender);
+    let shared_object_id = ObjectID::random();
+    let shared_object = {
+        let content = GasCoin::new(shared_object_id, 10);
+        let obj = MoveObject::new_gas_coin(OBJECT_START_VERSION, content.to_bcs_bytes());
+        Object::new_move(obj, Owner::Shared, TransactionDigest::genesis())
+    };
+    let authority_state = init_state_with_objects([gas_object.clone(), shared_object]).await;
+    let package = authority_state.get_framework_object_ref().await?;
+    let make_transaction = |shared_object_arg: ObjectArg| {
+        let call = SingleTransactionKind::Call(MoveCall {
+            package,
+            module: ident_str!("coin").to_owned(),
+            function: ident_str!("value").to_owned(),
+            type_arguments: vec![GAS::type_tag()],
+            arguments: vec![CallArg::Object(shared_object_arg)],
+        });
+        let data = TransactionData::new(
+            TransactionKind::Batch(vec![call.clone(), call]),
+            sender,
+            gas_object.compute_object_reference(),
+            100000,
+        );
+        to_sender_signed_transaction(data, &sender_key)
+    };
+
+    let tx = make_transaction(ObjectArg::ReadonlySharedObject(shared_object_id));
+    authority_state.handle_transaction(tx).await?;
+
+    let tx = make_transaction(ObjectArg::SharedObject(shared_object_id));
+    let response = authority_state.handle_transaction(tx).await;
+    assert!(matches!(
+        response.unwrap_err(),
+        SuiError::InvalidBatchTransaction {
+            error: BatchValidationError::DuplicateMutableObject(id),
+        } if id == shared_object_id
+    ));
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_batch_contains_publish() -> anyhow::Result<()> {
     // Test that a batch transaction containing publish will fail.
@@ -155,6 +155,7 @@ async fn submit_transaction_to_consensus() {
         committee,
         tx_consensus_listener,
         /* timeout */ Duration::from_secs(5),
+        /* submit_times */ Default::default(),
         metrics,
     );
 
@@ -199,6 +200,8 @@ async fn submit_transaction_to_consensus() {
             Err(e) => panic!("Unexpected error message: {e}"),
         }
     }
+    // The local submission time does not outlive the submission.
+    assert!(submitter.submit_times.lock().is_empty());
 
     // Ensure the consensus node got the transaction.
     let bytes = handle.recv().await.unwrap().transaction;
@@ -211,6 +214,32 @@ async fn submit_transaction_to_consensus() {
     }
 }
 
+#[tokio::test]
+async fn same_certificate_from_different_authorities_matches_waiter() {
+    let mut objects = test_gas_objects();
+    objects.push(test_shared_object());
+    let state = init_state_with_objects(objects).await;
+    let certificate = test_certificates(&state).await.pop().unwrap();
+
+    // The listener matches sequenced transactions to waiters by their serialized bytes, so
+    // the payload may only differ across authorities in the (skipped) tracking id.
+    let ours = bincode::serialize(&ConsensusTransaction::new_certificate_message(
+        &state.name,
+        certificate.clone(),
+    ))
+    .unwrap();
+    let theirs = bincode::serialize(&ConsensusTransaction::new_certificate_message(
+        &AuthorityName::ZERO,
+        certificate,
+    ))
+    .unwrap();
+    assert_ne!(ours, theirs);
+    assert_eq!(
+        ConsensusListener::hash_serialized_transaction(&ours),
+        ConsensusListener::hash_serialized_transaction(&theirs)
+    );
+}
+
 pub struct ConsensusMockServer {
     sender: Sender<TransactionProto>,
 }
@@ -3,15 +3,17 @@
 
 use super::*;
 use crate::{
-    authority::authority_tests::init_state_with_object_id,
+    authority::authority_tests::{init_state_with_object_id, init_state_with_objects},
     authority_client::{
         AuthorityAPI, LocalAuthorityClient, LocalAuthorityClientFaultConfig,
         NetworkAuthorityClient, NetworkAuthorityClientMetrics,
     },
+    consensus_adapter::consensus_tests::{test_certificates, test_gas_objects, test_shared_object},
     safe_client::SafeClientMetrics,
 };
 use futures::StreamExt;
 use std::sync::Arc;
+use sui_network::api::ValidatorClient;
 use sui_types::{
     base_types::{dbg_addr, dbg_object_id, ExecutionDigests},
     batch::UpdateItem,
@@ -506,3 +508,45 @@ async fn test_subscription_safe_client() {
 
     server.state.batch_notifier.close();
 }
+
+#[tokio::test]
+async fn test_handle_certificate_request_timeout() {
+    let mut objects = test_gas_objects();
+    objects.push(test_shared_object());
+    let authority_state = init_state_with_objects(objects).await;
+    let certificate = test_certificates(&authority_state).await.pop().unwrap();
+
+    // Keep the consensus listener channel full, so that the handler is stuck submitting the
+    // shared object certificate to consensus.
+    let consensus_address = "/ip4/127.0.0.1/tcp/0/http".parse().unwrap();
+    let (tx_consensus_listener, _rx_consensus_listener) = tokio::sync::mpsc::channel(1);
+    tx_consensus_listener
+        .send(ConsensusListenerMessage::Processed(vec![]))
+        .await
+        .unwrap();
+
+    let server = AuthorityServer::new_for_test(
+        "/ip4/127.0.0.1/tcp/0/http".parse().unwrap(),
+        Arc::new(authority_state),
+        consensus_address,
+        tx_consensus_listener,
+    );
+    let mut server_config = mysten_network::config::Config::new();
+    server_config.request_timeout = Some(Duration::from_millis(100));
+    let server_handle = server
+        .spawn_with_server_config_for_test(
+            "/ip4/127.0.0.1/tcp/0/http".parse().unwrap(),
+            server_config,
+        )
+        .await
+        .unwrap();
+
+    let channel = mysten_network::config::Config::new()
+        .connect_lazy(server_handle.address())
+        .unwrap();
+    let status = ValidatorClient::new(channel)
+        .handle_certificate(certificate)
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::DeadlineExceeded);
+}
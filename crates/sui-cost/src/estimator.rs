@@ -20,9 +20,9 @@ use sui_types::gas::GasCostSummary;
 use sui_types::gas::SuiGas;
 use sui_types::gas::MAX_GAS_BUDGET;
 use sui_types::gas_coin::GasCoin;
-use sui_types::messages::SingleTransactionKind;
 use sui_types::messages::TransactionData;
 use sui_types::messages::TransactionKind;
+use sui_types::messages::{MoveModulePublish, MovePackageUpgrade, SingleTransactionKind};
 use sui_types::temporary_store::TemporaryStore;
 
 const DEFAULT_COMPUTATION_GAS_UNIT_PRICE: u64 = 1;
@@ -99,8 +99,9 @@ fn estimate_transaction_inner<S>(
     // Steps 4 & 5
     for single_tx in tx.single_transactions() {
         match single_tx {
-            SingleTransactionKind::Publish(module) => {
-                gas_status.charge_publish_package(module.modules.iter().map(|v| v.len()).sum())?
+            SingleTransactionKind::Publish(MoveModulePublish { modules })
+            | SingleTransactionKind::Upgrade(MovePackageUpgrade { modules, .. }) => {
+                gas_status.charge_publish_package(modules.iter().map(|v| v.len()).sum())?
             }
             SingleTransactionKind::Call(_) => (),
             _ => continue,
@@ -51,6 +51,10 @@ module sui::sui_system {
         parameters: SystemParameters,
         /// The reference gas price for the current epoch.
         reference_gas_price: u64,
+        /// The round of the last consensus commit.
+        consensus_round: u64,
+        /// The timestamp of the last consensus commit, in milliseconds.
+        consensus_commit_timestamp_ms: u64,
     }
 
     // ==== functions that can only be called by Genesis ====
@@ -80,6 +84,8 @@ module sui::sui_system {
                 storage_gas_price
             },
             reference_gas_price,
+            consensus_round: 0,
+            consensus_commit_timestamp_ms: 0,
         };
         transfer::share_object(state);
     }
@@ -303,12 +309,34 @@ module sui::sui_system {
         balance::join(&mut self.storage_fund, computation_reward);
     }
 
+    /// Record the round and timestamp of a consensus commit. This is called through the
+    /// `ConsensusCommitPrologue` system transaction at the start of a consensus commit.
+    public entry fun consensus_commit_prologue(
+        self: &mut SuiSystemState,
+        round: u64,
+        commit_timestamp_ms: u64,
+        ctx: &mut TxContext,
+    ) {
+        // Validator will make a special system call with sender set as 0x0.
+        assert!(tx_context::sender(ctx) == @0x0, 0);
+        // Commit timestamps never go backwards.
+        assert!(commit_timestamp_ms >= self.consensus_commit_timestamp_ms, 0);
+        self.consensus_round = round;
+        self.consensus_commit_timestamp_ms = commit_timestamp_ms;
+    }
+
     /// Return the current epoch number. Useful for applications that need a coarse-grained concept of time,
     /// since epochs are ever-increasing and epoch changes are intended to happen every 24 hours.
     public fun epoch(self: &SuiSystemState): u64 {
         self.epoch
     }
 
+    /// Return the timestamp of the last consensus commit, in milliseconds. Useful for
+    /// applications that need a finer-grained concept of time than `epoch`.
+    public fun consensus_commit_timestamp_ms(self: &SuiSystemState): u64 {
+        self.consensus_commit_timestamp_ms
+    }
+
     /// Returns the amount of stake delegated to `validator_addr`.
     /// Aborts if `validator_addr` is not an active validator.
     public fun validator_delegate_amount(self: &SuiSystemState, validator_addr: address): u64 {
@@ -43,8 +43,8 @@ use sui_types::gas::GasCostSummary;
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
     CallArg, CertifiedTransaction, CertifiedTransactionEffects, ExecuteTransactionResponse,
-    ExecutionStatus, InputObjectKind, MoveModulePublish, ObjectArg, Pay, SingleTransactionKind,
-    TransactionData, TransactionEffects, TransactionKind,
+    ExecutionStatus, InputObjectKind, MoveModulePublish, MovePackageUpgrade, ObjectArg, Pay,
+    SingleTransactionKind, TransactionData, TransactionEffects, TransactionKind,
 };
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::move_package::{disassemble_modules, MovePackage};
@@ -60,6 +60,7 @@ mod rpc_types_tests;
 
 pub type SuiMoveTypeParameterIndex = u16;
 pub type TransactionsPage = Page<TransactionDigest, TransactionDigest>;
+pub type EffectsPage = Page<SuiTransactionEffects, CheckpointSequenceNumber>;
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum SuiMoveAbility {
@@ -370,6 +371,11 @@ pub enum SuiExecuteTransactionResponse {
         // before this response.
         confirmed_local_execution: bool,
     },
+    // Uncertified effects returned by the most stake, for BestEffortWithPartialEffects
+    // requests when validators don't agree on effects with a quorum.
+    PartialEffects {
+        effects: SuiTransactionEffects,
+    },
 }
 
 impl SuiExecuteTransactionResponse {
@@ -398,6 +404,11 @@ impl SuiExecuteTransactionResponse {
                     confirmed_local_execution: is_executed_locally,
                 }
             }
+            ExecuteTransactionResponse::PartialEffects(effects) => {
+                SuiExecuteTransactionResponse::PartialEffects {
+                    effects: SuiTransactionEffects::try_from(*effects, resolver)?,
+                }
+            }
         })
     }
 }
@@ -946,6 +957,18 @@ impl Display for SuiParsedPublishResponse {
 pub type GetObjectDataResponse = SuiObjectRead<SuiParsedData>;
 pub type GetRawObjectDataResponse = SuiObjectRead<SuiRawData>;
 
+/// An object together with the layout of its Move struct, built per the requested
+/// `ObjectFormatOptions`.
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename = "ObjectWithLayout")]
+pub struct SuiObjectWithLayout {
+    pub object: GetObjectDataResponse,
+    /// The layout of the object's Move struct, absent for packages and for objects that
+    /// do not exist or were deleted.
+    #[schemars(with = "Option<Value>")]
+    pub layout: Option<MoveStructLayout>,
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(tag = "status", content = "details", rename = "ObjectRead")]
 pub enum SuiObjectRead<T: SuiData> {
@@ -1355,6 +1378,26 @@ impl TryFrom<MoveModulePublish> for SuiMovePackage {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename = "MovePackageUpgrade")]
+pub struct SuiMovePackageUpgrade {
+    pub package: ObjectID,
+    pub disassembled: BTreeMap<String, Value>,
+    pub ticket: SuiObjectRef,
+}
+
+impl TryFrom<MovePackageUpgrade> for SuiMovePackageUpgrade {
+    type Error = anyhow::Error;
+
+    fn try_from(u: MovePackageUpgrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            package: u.package,
+            disassembled: disassemble_modules(u.modules.iter())?,
+            ticket: u.ticket.into(),
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
 #[serde(rename = "Pay")]
 pub struct SuiPay {
@@ -1440,6 +1483,10 @@ pub enum SuiTransactionKind {
     TransferSui(SuiTransferSui),
     /// A system transaction that will update epoch information on-chain.
     ChangeEpoch(SuiChangeEpoch),
+    /// A system transaction that stamps the consensus commit timestamp on-chain.
+    ConsensusCommitPrologue(SuiConsensusCommitPrologue),
+    /// Upgrade the modules of a published Move package
+    Upgrade(SuiMovePackageUpgrade),
     // .. more transaction types go here
 }
 
@@ -1485,6 +1532,11 @@ impl Display for SuiTransactionKind {
             Self::Publish(_p) => {
                 write!(writer, "Transaction Kind : Publish")?;
             }
+            Self::Upgrade(u) => {
+                writeln!(writer, "Transaction Kind : Upgrade")?;
+                writeln!(writer, "Package ID : {}", u.package.to_hex_literal())?;
+                write!(writer, "Ticket ID : {}", u.ticket.object_id)?;
+            }
             Self::Call(c) => {
                 writeln!(writer, "Transaction Kind : Call")?;
                 writeln!(
@@ -1503,6 +1555,11 @@ impl Display for SuiTransactionKind {
                 writeln!(writer, "Storage gas reward: {}", e.storage_charge)?;
                 writeln!(writer, "Computation gas reward: {}", e.computation_charge)?;
             }
+            Self::ConsensusCommitPrologue(p) => {
+                writeln!(writer, "Transaction Kind : Consensus Commit Prologue")?;
+                writeln!(writer, "Round : {}", p.round)?;
+                writeln!(writer, "Commit Timestamp : {}", p.commit_timestamp_ms)?;
+            }
         }
         write!(f, "{}", writer)
     }
@@ -1523,6 +1580,7 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
             }),
             SingleTransactionKind::Pay(p) => Self::Pay(p.into()),
             SingleTransactionKind::Publish(p) => Self::Publish(p.try_into()?),
+            SingleTransactionKind::Upgrade(u) => Self::Upgrade(u.try_into()?),
             SingleTransactionKind::Call(c) => Self::Call(SuiMoveCall {
                 package: c.package.into(),
                 module: c.module.to_string(),
@@ -1534,14 +1592,18 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
                     .map(|arg| match arg {
                         CallArg::Pure(p) => SuiJsonValue::from_bcs_bytes(&p),
                         CallArg::Object(ObjectArg::ImmOrOwnedObject((id, _, _)))
-                        | CallArg::Object(ObjectArg::SharedObject(id)) => {
+                        | CallArg::Object(ObjectArg::Receiving((id, _, _)))
+                        | CallArg::Object(ObjectArg::SharedObject(id))
+                        | CallArg::Object(ObjectArg::ReadonlySharedObject(id)) => {
                             SuiJsonValue::new(Value::String(id.to_hex_literal()))
                         }
                         CallArg::ObjVec(vec) => SuiJsonValue::new(Value::Array(
                             vec.iter()
                                 .map(|obj_arg| match obj_arg {
                                     ObjectArg::ImmOrOwnedObject((id, _, _))
-                                    | ObjectArg::SharedObject(id) => {
+                                    | ObjectArg::Receiving((id, _, _))
+                                    | ObjectArg::SharedObject(id)
+                                    | ObjectArg::ReadonlySharedObject(id) => {
                                         Value::String(id.to_hex_literal())
                                     }
                                 })
@@ -1555,6 +1617,12 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
                 storage_charge: e.storage_charge,
                 computation_charge: e.computation_charge,
             }),
+            SingleTransactionKind::ConsensusCommitPrologue(p) => {
+                Self::ConsensusCommitPrologue(SuiConsensusCommitPrologue {
+                    round: p.round,
+                    commit_timestamp_ms: p.commit_timestamp_ms,
+                })
+            }
         })
     }
 }
@@ -1578,6 +1646,12 @@ pub struct SuiChangeEpoch {
     pub computation_charge: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SuiConsensusCommitPrologue {
+    pub round: u64,
+    pub commit_timestamp_ms: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename = "CertifiedTransaction", rename_all = "camelCase")]
 pub struct SuiCertifiedTransaction {
@@ -2169,6 +2243,8 @@ pub enum SuiInputObjectKind {
     ImmOrOwnedMoveObject(SuiObjectRef),
     // A Move object that's shared and mutable.
     SharedMoveObject(ObjectID),
+    // A Move object that's shared and only read.
+    ReadonlySharedMoveObject(ObjectID),
 }
 
 impl From<InputObjectKind> for SuiInputObjectKind {
@@ -2177,6 +2253,7 @@ impl From<InputObjectKind> for SuiInputObjectKind {
             InputObjectKind::MovePackage(id) => Self::MovePackage(id),
             InputObjectKind::ImmOrOwnedMoveObject(oref) => Self::ImmOrOwnedMoveObject(oref.into()),
             InputObjectKind::SharedMoveObject(id) => Self::SharedMoveObject(id),
+            InputObjectKind::ReadonlySharedMoveObject(id) => Self::ReadonlySharedMoveObject(id),
         }
     }
 }
@@ -8,11 +8,12 @@ use jsonrpsee_proc_macros::rpc;
 
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, GetPastObjectDataResponse, GetRawObjectDataResponse,
+    EffectsPage, GetObjectDataResponse, GetPastObjectDataResponse, GetRawObjectDataResponse,
     MoveFunctionArgType, RPCTransactionRequestParams, SuiEventEnvelope, SuiEventFilter,
     SuiExecuteTransactionResponse, SuiGasCostSummary, SuiMoveNormalizedFunction,
-    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo, SuiTransactionEffects,
-    SuiTransactionFilter, SuiTransactionResponse, SuiTypeTag, TransactionBytes, TransactionsPage,
+    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo, SuiObjectWithLayout,
+    SuiTransactionEffects, SuiTransactionFilter, SuiTransactionResponse, SuiTypeTag,
+    TransactionBytes, TransactionsPage,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TransactionDigest};
@@ -21,7 +22,8 @@ use sui_types::committee::EpochId;
 use sui_types::crypto::SignatureScheme;
 use sui_types::messages::CommitteeInfoResponse;
 use sui_types::messages::ExecuteTransactionRequestType;
-use sui_types::object::Owner;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::{ObjectFormatOptions, Owner};
 use sui_types::query::{Ordering, TransactionQuery};
 use sui_types::sui_serde::Base64;
 
@@ -31,6 +33,9 @@ use sui_types::sui_serde::Base64;
 /// for document purposes.
 pub const MAX_RESULT_SIZE: usize = 4096;
 
+/// Maximum number of object ids accepted by a single `sui_multiGetObjects` request.
+pub const MAX_MULTI_GET_OBJECTS: usize = 1000;
+
 #[open_rpc(namespace = "sui", tag = "Gateway Transaction Execution API")]
 #[rpc(server, client, namespace = "sui")]
 pub trait RpcGatewayApi {
@@ -109,6 +114,40 @@ pub trait RpcReadApi {
         /// the ID of the queried object
         object_id: ObjectID,
     ) -> RpcResult<GetObjectDataResponse>;
+
+    /// Return the object information for each of the specified objects, in the order requested.
+    /// At most MAX_MULTI_GET_OBJECTS ids may be queried at once.
+    #[method(name = "multiGetObjects")]
+    async fn multi_get_objects(
+        &self,
+        /// the IDs of the queried objects
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>>;
+
+    /// Return the object information for a specified object, along with the layout of its
+    /// Move struct in the requested format
+    #[method(name = "getObjectWithLayout")]
+    async fn get_object_with_layout(
+        &self,
+        /// the ID of the queried object
+        object_id: ObjectID,
+        /// the format of the returned layout
+        options: ObjectFormatOptions,
+    ) -> RpcResult<SuiObjectWithLayout>;
+
+    /// Return the effects of the transactions in the checkpoints from `checkpoint` onwards, in
+    /// checkpoint order. A page holds whole checkpoints and stops before exceeding `limit`
+    /// effects, except that its first checkpoint is always included. A page also ends before
+    /// the first checkpoint whose effects this node has not stored yet. `nextCursor` is the
+    /// checkpoint to continue from, which is `checkpoint` itself if the page is empty.
+    #[method(name = "getEffectsSinceCheckpoint")]
+    async fn get_effects_since_checkpoint(
+        &self,
+        /// the sequence number of the first checkpoint to return effects for
+        checkpoint: CheckpointSequenceNumber,
+        /// Maximum number of effects returned per page, default to [MAX_RESULT_SIZE] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<EffectsPage>;
 }
 
 #[open_rpc(namespace = "sui", tag = "Full Node API")]
@@ -192,7 +231,11 @@ pub trait RpcFullNodeReadApi {
         /// the version of the queried object. If None, default to the latest known version
         version: SequenceNumber,
     ) -> RpcResult<GetPastObjectDataResponse>;
+}
 
+#[open_rpc(namespace = "sui", tag = "Committee API")]
+#[rpc(server, client, namespace = "sui")]
+pub trait RpcCommitteeApi {
     /// Return the committee information for the asked epoch
     #[method(name = "getCommitteeInfo")]
     async fn get_committee_info(
@@ -505,6 +548,9 @@ pub trait TransactionExecutionApi {
     ///     makes sure this node is aware of this transaction when client fires subsequent queries.
     ///     However if the node fails to execute the transaction locally in a timely manner,
     ///     a bool type in the response is set to false to indicated the case.
+    /// 5. BestEffortWithPartialEffects: waits for TransactionEffectsCert like WaitForEffectsCert.
+    ///     If validators don't agree on effects with a quorum, the effects returned by the most
+    ///     stake are returned instead, which are not certified.
     #[method(name = "executeTransaction")]
     async fn execute_transaction(
         &self,
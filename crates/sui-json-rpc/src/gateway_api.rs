@@ -3,6 +3,7 @@
 
 use crate::api::{
     RpcGatewayApiServer, RpcReadApiServer, RpcTransactionBuilderServer, WalletSyncApiServer,
+    MAX_MULTI_GET_OBJECTS,
 };
 use crate::SuiRpcModule;
 use anyhow::anyhow;
@@ -13,8 +14,8 @@ use signature::Signature;
 use sui_core::gateway_state::GatewayClient;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, RPCTransactionRequestParams, SuiObjectInfo, SuiTransactionResponse,
-    SuiTypeTag, TransactionBytes,
+    GetObjectDataResponse, RPCTransactionRequestParams, SuiObjectInfo, SuiObjectWithLayout,
+    SuiTransactionResponse, SuiTypeTag, TransactionBytes,
 };
 use sui_open_rpc::Module;
 use sui_types::batch::TxSequenceNumber;
@@ -25,6 +26,7 @@ use sui_types::{
     crypto,
     crypto::SignableBytes,
     messages::{Transaction, TransactionData},
+    object::ObjectFormatOptions,
 };
 use tracing::debug;
 
@@ -141,6 +143,34 @@ impl RpcReadApiServer for GatewayReadApiImpl {
         Ok(self.client.get_object(object_id).await?)
     }
 
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        if object_ids.len() > MAX_MULTI_GET_OBJECTS {
+            Err(anyhow!(
+                "Too many object ids requested: {}, the maximum is {MAX_MULTI_GET_OBJECTS}.",
+                object_ids.len()
+            ))?;
+        }
+        let mut responses = Vec::with_capacity(object_ids.len());
+        for object_id in object_ids {
+            responses.push(self.client.get_object(object_id).await?);
+        }
+        Ok(responses)
+    }
+
+    async fn get_object_with_layout(
+        &self,
+        object_id: ObjectID,
+        options: ObjectFormatOptions,
+    ) -> RpcResult<SuiObjectWithLayout> {
+        Ok(self
+            .client
+            .get_object_with_layout(object_id, options)
+            .await?)
+    }
+
     async fn get_transaction(
         &self,
         digest: TransactionDigest,
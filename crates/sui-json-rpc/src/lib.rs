@@ -17,6 +17,9 @@ use prometheus::{
 };
 use tracing::info;
 
+use anyhow::anyhow;
+use sui_types::error::SuiError;
+
 use sui_open_rpc::{Module, Project};
 
 use crate::http_server::{HttpServerBuilder, HttpServerHandle};
@@ -156,11 +159,40 @@ impl JsonRpcServerBuilder {
         })
     }
 
+    /// Caps the number of subscriptions a single websocket connection may hold at once.
+    /// HTTP servers do not support subscriptions and are left unchanged.
+    pub fn max_subscriptions_per_connection(mut self, max: usize) -> Self {
+        self.server_builder = match self.server_builder {
+            ServerBuilder::WsBuilder(ws_builder) => ServerBuilder::WsBuilder(
+                ws_builder.max_subscriptions_per_connection(max.try_into().unwrap_or(u32::MAX)),
+            ),
+            http_builder => http_builder,
+        };
+        self
+    }
+
     pub fn register_module<T: SuiRpcModule>(&mut self, module: T) -> Result<(), anyhow::Error> {
         self.rpc_doc.add_module(T::rpc_doc_module());
         Ok(self.module.merge(module.rpc())?)
     }
 
+    /// Registers every method of `T` to fail with `SuiError::EndpointNotAvailableOnRole`, for
+    /// modules that nodes in `role` do not serve. Clients get that error instead of the generic
+    /// method-not-found. The methods are left out of `rpc.discover`.
+    pub fn register_unavailable_module<T: SuiRpcModule>(
+        &mut self,
+        role: &'static str,
+    ) -> Result<(), anyhow::Error> {
+        for name in T::rpc_doc_module().method_names() {
+            // Method names must be 'static; this runs once per method at startup.
+            let name: &'static str = Box::leak(name.to_owned().into_boxed_str());
+            self.module.register_method(name, move |_, _| {
+                Err::<(), _>(anyhow!(SuiError::EndpointNotAvailableOnRole { role }).into())
+            })?;
+        }
+        Ok(())
+    }
+
     pub async fn start(
         mut self,
         listen_address: SocketAddr,
@@ -14,9 +14,10 @@ use signature::Signature;
 
 use sui_core::authority::AuthorityState;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, GetPastObjectDataResponse, MoveFunctionArgType, ObjectValueKind, Page,
-    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo,
-    SuiTransactionEffects, SuiTransactionResponse, TransactionsPage,
+    EffectsPage, GetObjectDataResponse, GetPastObjectDataResponse, MoveFunctionArgType,
+    ObjectValueKind, Page, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
+    SuiMoveNormalizedStruct, SuiObjectInfo, SuiObjectWithLayout, SuiTransactionEffects,
+    SuiTransactionResponse, TransactionsPage,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::SequenceNumber;
@@ -27,13 +28,16 @@ use sui_types::crypto::{SignableBytes, SignatureScheme};
 use sui_types::messages::{
     CommitteeInfoRequest, CommitteeInfoResponse, Transaction, TransactionData,
 };
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::move_package::normalize_modules;
-use sui_types::object::{Data, ObjectRead, Owner};
+use sui_types::object::{Data, ObjectFormatOptions, ObjectRead, Owner};
 use sui_types::query::{Ordering, TransactionQuery};
 use sui_types::sui_serde::Base64;
 
 use crate::api::RpcReadApiServer;
-use crate::api::{RpcFullNodeReadApiServer, MAX_RESULT_SIZE};
+use crate::api::{
+    RpcCommitteeApiServer, RpcFullNodeReadApiServer, MAX_MULTI_GET_OBJECTS, MAX_RESULT_SIZE,
+};
 use crate::SuiRpcModule;
 
 // An implementation of the read portion of the Gateway JSON-RPC interface intended for use in
@@ -52,6 +56,17 @@ impl FullNodeApi {
     }
 }
 
+/// Read-only committee queries, served by both full nodes and validators.
+pub struct CommitteeApi {
+    pub state: Arc<AuthorityState>,
+}
+
+impl CommitteeApi {
+    pub fn new(state: Arc<AuthorityState>) -> Self {
+        Self { state }
+    }
+}
+
 impl ReadApi {
     pub fn new(state: Arc<AuthorityState>) -> Self {
         Self { state }
@@ -95,6 +110,45 @@ impl RpcReadApiServer for ReadApi {
             .try_into()?)
     }
 
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        if object_ids.len() > MAX_MULTI_GET_OBJECTS {
+            Err(anyhow!(
+                "Too many object ids requested: {}, the maximum is {MAX_MULTI_GET_OBJECTS}.",
+                object_ids.len()
+            ))?;
+        }
+        let mut responses = Vec::with_capacity(object_ids.len());
+        for object_id in object_ids {
+            responses.push(self.get_object(object_id).await?);
+        }
+        Ok(responses)
+    }
+
+    async fn get_object_with_layout(
+        &self,
+        object_id: ObjectID,
+        options: ObjectFormatOptions,
+    ) -> RpcResult<SuiObjectWithLayout> {
+        let object_read = self
+            .state
+            .get_object_read(&object_id)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+        let layout = match &object_read {
+            ObjectRead::Exists(_, object, _) => object
+                .get_layout(options, self.state.module_cache.as_ref())
+                .map_err(|e| anyhow!("{e}"))?,
+            _ => None,
+        };
+        Ok(SuiObjectWithLayout {
+            object: object_read.try_into()?,
+            layout,
+        })
+    }
+
     async fn get_total_transaction_number(&self) -> RpcResult<u64> {
         Ok(self.state.get_total_transaction_number()?)
     }
@@ -124,6 +178,28 @@ impl RpcReadApiServer for ReadApi {
             parsed_data: None,
         })
     }
+
+    async fn get_effects_since_checkpoint(
+        &self,
+        checkpoint: CheckpointSequenceNumber,
+        limit: Option<usize>,
+    ) -> RpcResult<EffectsPage> {
+        let limit = limit.unwrap_or(MAX_RESULT_SIZE).min(MAX_RESULT_SIZE);
+        if limit == 0 {
+            Err(anyhow!("Page result limit must be larger then 0."))?;
+        }
+        let (effects, next_cursor) = self.state.get_effects_since(checkpoint, limit)?;
+        let data = effects
+            .into_iter()
+            .map(|effects| {
+                SuiTransactionEffects::try_from(effects.effects, self.state.module_cache.as_ref())
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Page {
+            data,
+            next_cursor: Some(next_cursor),
+        })
+    }
 }
 
 impl SuiRpcModule for ReadApi {
@@ -300,7 +376,20 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
             .map_err(|e| anyhow!("{e}"))?
             .try_into()?)
     }
+}
 
+impl SuiRpcModule for FullNodeApi {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+
+    fn rpc_doc_module() -> Module {
+        crate::api::RpcFullNodeReadApiOpenRpc::module_doc()
+    }
+}
+
+#[async_trait]
+impl RpcCommitteeApiServer for CommitteeApi {
     async fn get_committee_info(&self, epoch: Option<EpochId>) -> RpcResult<CommitteeInfoResponse> {
         Ok(self
             .state
@@ -309,13 +398,13 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
     }
 }
 
-impl SuiRpcModule for FullNodeApi {
+impl SuiRpcModule for CommitteeApi {
     fn rpc(self) -> RpcModule<Self> {
         self.into_rpc()
     }
 
     fn rpc_doc_module() -> Module {
-        crate::api::RpcFullNodeReadApiOpenRpc::module_doc()
+        crate::api::RpcCommitteeApiOpenRpc::module_doc()
     }
 }
 
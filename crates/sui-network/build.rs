@@ -98,6 +98,15 @@ fn main() -> Result<()> {
                 .name("committee_info")
                 .route_name("CommitteeInfo")
                 .input_type("sui_types::messages::CommitteeInfoRequest")
+                .output_type("sui_types::messages::LegacyCommitteeInfoResponse")
+                .codec_path(codec_path)
+                .build(),
+        )
+        .method(
+            Method::builder()
+                .name("committee_info_v2")
+                .route_name("CommitteeInfoV2")
+                .input_type("sui_types::messages::CommitteeInfoRequest")
                 .output_type("sui_types::messages::CommitteeInfoResponse")
                 .codec_path(codec_path)
                 .build(),
@@ -23,7 +23,7 @@ use sui_core::safe_client::SafeClientMetrics;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
 use sui_core::transaction_streamer::TransactionStreamer;
 use sui_core::{
-    authority::{AuthorityState, AuthorityStore},
+    authority::{AuthorityState, AuthorityStore, ExecutionFailureReceiver},
     authority_active::{gossip::GossipMetrics, ActiveAuthority},
     authority_client::{
         make_network_authority_client_sets_from_genesis,
@@ -41,17 +41,20 @@ use sui_storage::{
     node_sync_store::NodeSyncStore,
     IndexStore,
 };
+use sui_types::base_types::{AuthorityName, TransactionDigest};
+use sui_types::committee::{EpochId, StakeUnit};
 use sui_types::messages::{CertifiedTransaction, CertifiedTransactionEffects};
 use tokio::sync::mpsc::channel;
 use tower::ServiceBuilder;
 use tracing::{error, info, warn};
 
-use crate::metrics::GrpcMetrics;
+use crate::metrics::{ConnectionChurnMetrics, GrpcMetrics};
 use sui_core::authority_client::NetworkAuthorityClientMetrics;
 use sui_core::epoch::committee_store::CommitteeStore;
 use sui_json_rpc::event_api::EventReadApiImpl;
 use sui_json_rpc::event_api::EventStreamingApiImpl;
 use sui_json_rpc::http_server::HttpServerHandle;
+use sui_json_rpc::read_api::CommitteeApi;
 use sui_json_rpc::read_api::FullNodeApi;
 use sui_json_rpc::read_api::ReadApi;
 use sui_json_rpc::transaction_execution_api::FullNodeTransactionExecutionApi;
@@ -65,6 +68,8 @@ pub mod metrics;
 mod handle;
 pub use handle::SuiNodeHandle;
 
+const FAILURE_NOTIFICATION_CAPACITY: usize = 1_000;
+
 pub struct SuiNode {
     grpc_server: tokio::task::JoinHandle<Result<()>>,
     _json_rpc_service: Option<HttpServerHandle>,
@@ -80,6 +85,7 @@ pub struct SuiNode {
     _prometheus_registry: Registry,
 
     _p2p_network: anemo::Network,
+    _connection_churn_monitor_handle: tokio::task::JoinHandle<()>,
 
     #[cfg(msim)]
     sim_node: sui_simulator::runtime::NodeHandle,
@@ -140,6 +146,10 @@ impl SuiNode {
             .websocket_address
             .map(|_| Arc::new(TransactionStreamer::new()));
 
+        let failure_notifier = config
+            .enable_failure_notifications
+            .then(|| tokio::sync::broadcast::channel(FAILURE_NOTIFICATION_CAPACITY).0);
+
         let node_sync_store = Arc::new(NodeSyncStore::open_tables_read_write(
             config.db_path().join("node_sync_db"),
             None,
@@ -156,6 +166,7 @@ impl SuiNode {
                 index_store.clone(),
                 event_store,
                 transaction_streamer,
+                failure_notifier,
                 checkpoint_store,
                 genesis,
                 &prometheus_registry,
@@ -163,6 +174,24 @@ impl SuiNode {
             )
             .await,
         );
+        if let Some(version) = config.enabled_transaction_version {
+            state.enable_transaction_version(version)?;
+        }
+
+        // Warm the module cache before anything can execute transactions or serve requests.
+        if !config.preload_packages.is_empty() {
+            let now = Instant::now();
+            match state.preload_packages(&config.preload_packages).await {
+                Ok(modules) => info!(
+                    "Preloaded {} modules from {} packages into the module cache in {:?}",
+                    modules,
+                    config.preload_packages.len(),
+                    now.elapsed()
+                ),
+                Err(err) => warn!("Failed to preload the module cache: {:?}", err),
+            }
+        }
+
         let net_config = default_mysten_network_config();
 
         let sui_system_state = state.get_sui_system_state_object().await?;
@@ -225,12 +254,18 @@ impl SuiNode {
         let post_processing_subsystem_handle =
             if index_store.is_some() || config.enable_event_processing {
                 let indexing_state = state.clone();
-                Some(tokio::task::spawn(async move {
+                let post_processing = async move {
                     indexing_state
                         .run_tx_post_processing_process()
                         .await
                         .map_err(Into::into)
-                }))
+                };
+                Some(match config.post_processing_threads {
+                    Some(threads) => {
+                        spawn_on_dedicated_runtime("sui-post-processing", threads, post_processing)?
+                    }
+                    None => tokio::task::spawn(post_processing),
+                })
             } else {
                 None
             };
@@ -258,7 +293,10 @@ impl SuiNode {
         } else {
             None
         };
-        let execute_driver_handle = active_authority.clone().spawn_execute_process().await;
+        let execute_driver_handle = active_authority
+            .clone()
+            .spawn_execute_process_with_limit(config.max_concurrent_executions)
+            .await;
         let checkpoint_process_handle = if config.enable_checkpoint && is_validator {
             Some(
                 active_authority
@@ -281,9 +319,7 @@ impl SuiNode {
         };
 
         let grpc_server = {
-            let mut server_conf = mysten_network::config::Config::new();
-            server_conf.global_concurrency_limit = config.grpc_concurrency_limit;
-            server_conf.load_shed = config.grpc_load_shed;
+            let server_conf = grpc_server_config(config);
             let mut server_builder =
                 ServerBuilder::from_config(&server_conf, GrpcMetrics::new(&prometheus_registry));
 
@@ -301,7 +337,7 @@ impl SuiNode {
             tokio::spawn(server.serve().map_err(Into::into))
         };
 
-        let p2p_network = {
+        let (p2p_network, connection_churn_monitor_handle) = {
             let inbound_network_metrics =
                 NetworkMetrics::new("sui", "inbound", &prometheus_registry);
             let outbound_network_metrics =
@@ -338,8 +374,10 @@ impl SuiNode {
                     network.downgrade(),
                     network_connection_metrics,
                 );
+            let connection_churn_monitor_handle = ConnectionChurnMetrics::new(&prometheus_registry)
+                .spawn_monitor(network.downgrade());
 
-            network
+            (network, connection_churn_monitor_handle)
         };
 
         let (json_rpc_service, ws_subscription_service) = build_http_servers(
@@ -364,6 +402,7 @@ impl SuiNode {
             transaction_orchestrator,
             _prometheus_registry: prometheus_registry,
             _p2p_network: p2p_network,
+            _connection_churn_monitor_handle: connection_churn_monitor_handle,
 
             #[cfg(msim)]
             sim_node: sui_simulator::runtime::NodeHandle::current(),
@@ -398,6 +437,38 @@ impl SuiNode {
             .ok_or_else(|| anyhow::anyhow!("Transaction Orchestrator is not enabled in this node."))
     }
 
+    /// Subscribe to the digest and failure status of every transaction whose execution
+    /// fails on this node.
+    pub fn subscribe_to_failures(&self) -> Result<ExecutionFailureReceiver> {
+        self.state
+            .subscribe_to_execution_failures()
+            .ok_or_else(|| anyhow::anyhow!("Failure notifications are not enabled in this node."))
+    }
+
+    /// The committee of every epoch this node knows about, in epoch order, for verifying
+    /// historical data.
+    pub fn all_known_committees(&self) -> Vec<(EpochId, Vec<(AuthorityName, StakeUnit)>)> {
+        self.state
+            .committee_store()
+            .get_all_committees()
+            .into_iter()
+            .map(|committee| (committee.epoch, committee.voting_rights))
+            .collect()
+    }
+
+    /// Subscribe to the digest of every transaction executed on this node from now on.
+    pub fn subscribe_to_executed_digests(
+        &self,
+    ) -> Result<tokio::sync::broadcast::Receiver<TransactionDigest>> {
+        Ok(self.state.subscribe_to_executed_digests())
+    }
+
+    /// Whether the node is in the middle of an epoch change, during which the committee may
+    /// change and submitted transactions may be rejected.
+    pub fn is_reconfiguring(&self) -> bool {
+        self.state.is_reconfiguring()
+    }
+
     //TODO watch/wait on all the components
     pub async fn wait(self) -> Result<()> {
         self.grpc_server.await??;
@@ -406,14 +477,50 @@ impl SuiNode {
     }
 }
 
+/// Spawn `future` on a new multi-threaded runtime with `threads` worker threads. The runtime
+/// is driven from a blocking thread of the current runtime, so the returned handle can be
+/// awaited like any other task's. The runtime shuts down once `future` completes.
+fn spawn_on_dedicated_runtime<F>(
+    name: &str,
+    threads: usize,
+    future: F,
+) -> Result<tokio::task::JoinHandle<F::Output>>
+where
+    F: std::future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(threads)
+        .thread_name(name)
+        .enable_all()
+        .build()?;
+    Ok(tokio::task::spawn_blocking(move || {
+        let handle = runtime.spawn(future);
+        runtime
+            .block_on(handle)
+            .unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()))
+    }))
+}
+
+/// Build the gRPC server configuration from the node config. The request timeout is
+/// applied by the server as a timeout layer around every service, including `ValidatorService`.
+fn grpc_server_config(config: &NodeConfig) -> mysten_network::config::Config {
+    let mut server_conf = mysten_network::config::Config::new();
+    server_conf.global_concurrency_limit = config.grpc_concurrency_limit;
+    server_conf.load_shed = config.grpc_load_shed;
+    server_conf.request_timeout = config.grpc_request_timeout_ms.map(Duration::from_millis);
+    server_conf
+}
+
 pub async fn build_http_servers(
     state: Arc<AuthorityState>,
     transaction_orchestrator: &Option<Arc<TransactiondOrchestrator<NetworkAuthorityClient>>>,
     config: &NodeConfig,
     prometheus_registry: &Registry,
 ) -> Result<(Option<HttpServerHandle>, Option<WsServerHandle>)> {
-    // Validators do not expose these APIs
-    if config.consensus_config().is_some() {
+    let is_validator = config.consensus_config().is_some();
+    // Validators do not expose these APIs, unless read-only RPC is explicitly enabled.
+    if is_validator && !config.validator_enable_read_rpc {
         return Ok((None, None));
     }
 
@@ -428,6 +535,24 @@ pub async fn build_http_servers(
         JsonRpcServerBuilder::new(env!("CARGO_PKG_VERSION"), false, prometheus_registry)?;
 
     server.register_module(ReadApi::new(state.clone()))?;
+    server.register_module(CommitteeApi::new(state.clone()))?;
+
+    if is_validator {
+        // Validators only serve the read-only modules above. Requests for the full node
+        // modules get an explicit error instead of method-not-found.
+        server.register_unavailable_module::<FullNodeApi>("validator")?;
+        server.register_unavailable_module::<BcsApiImpl>("validator")?;
+        server.register_unavailable_module::<FullNodeTransactionBuilderApi>("validator")?;
+        server.register_unavailable_module::<FullNodeTransactionExecutionApi>("validator")?;
+        server.register_unavailable_module::<EventReadApiImpl>("validator")?;
+        let rpc_server_handle = server
+            .start(config.json_rpc_address)
+            .await?
+            .into_http_server_handle()
+            .expect("Expect a http server handle");
+        return Ok((Some(rpc_server_handle), None));
+    }
+
     server.register_module(FullNodeApi::new(state.clone()))?;
     server.register_module(BcsApiImpl::new(state.clone()))?;
     server.register_module(FullNodeTransactionBuilderApi::new(state.clone()))?;
@@ -453,6 +578,9 @@ pub async fn build_http_servers(
         Some(ws_addr) => {
             let mut server =
                 JsonRpcServerBuilder::new(env!("CARGO_PKG_VERSION"), true, prometheus_registry)?;
+            if let Some(max) = config.max_subscriptions_per_connection {
+                server = server.max_subscriptions_per_connection(max);
+            }
             if let Some(tx_streamer) = state.transaction_streamer.clone() {
                 server.register_module(TransactionStreamingApiImpl::new(
                     state.clone(),
@@ -476,3 +604,38 @@ pub async fn build_http_servers(
     };
     Ok((Some(rpc_server_handle), ws_server_handle))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_config::builder::ConfigBuilder;
+
+    #[tokio::test]
+    async fn test_spawn_on_dedicated_runtime() {
+        let handle = spawn_on_dedicated_runtime("test-dedicated-runtime", 2, async {
+            tokio::task::yield_now().await;
+            std::thread::current().name().map(str::to_owned)
+        })
+        .unwrap();
+        assert_eq!(
+            handle.await.unwrap().as_deref(),
+            Some("test-dedicated-runtime")
+        );
+    }
+
+    #[test]
+    fn test_grpc_request_timeout_config() {
+        let mut config = ConfigBuilder::new(std::env::temp_dir())
+            .build()
+            .validator_configs[0]
+            .clone();
+
+        assert_eq!(grpc_server_config(&config).request_timeout, None);
+
+        config.grpc_request_timeout_ms = Some(250);
+        assert_eq!(
+            grpc_server_config(&config).request_timeout,
+            Some(Duration::from_millis(250))
+        );
+    }
+}
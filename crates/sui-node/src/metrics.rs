@@ -7,9 +7,11 @@ use prometheus::{
     register_int_counter_vec_with_registry, register_int_gauge_vec_with_registry, IntCounterVec,
     IntGaugeVec, Registry, TextEncoder,
 };
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::time::Duration;
 use sui_network::tonic::Code;
+use tokio::task::JoinHandle;
 
 use tracing::warn;
 
@@ -97,3 +99,148 @@ impl MetricsCallbackProvider for GrpcMetrics {
         self.inflight_grpc.with_label_values(&[path]).dec();
     }
 }
+
+/// Direction of a P2P connection relative to this node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionDirection {
+    Inbound,
+    Outbound,
+}
+
+impl ConnectionDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ConnectionDirection::Inbound => "inbound",
+            ConnectionDirection::Outbound => "outbound",
+        }
+    }
+}
+
+impl From<anemo::types::ConnectionOrigin> for ConnectionDirection {
+    fn from(origin: anemo::types::ConnectionOrigin) -> Self {
+        match origin {
+            anemo::types::ConnectionOrigin::Inbound => ConnectionDirection::Inbound,
+            anemo::types::ConnectionOrigin::Outbound => ConnectionDirection::Outbound,
+        }
+    }
+}
+
+/// Connects and disconnects of P2P peers, so that operators can alert on churn
+/// (e.g. `rate(p2p_peer_disconnects[1m])`). Complements the per-peer connection status
+/// reported by `NetworkConnectionMetrics`.
+#[derive(Clone)]
+pub struct ConnectionChurnMetrics {
+    peer_connects: IntCounterVec,
+    peer_disconnects: IntCounterVec,
+}
+
+impl ConnectionChurnMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            peer_connects: register_int_counter_vec_with_registry!(
+                "p2p_peer_connects",
+                "Total P2P peer connections established, by direction",
+                &["direction"],
+                registry,
+            )
+            .unwrap(),
+            peer_disconnects: register_int_counter_vec_with_registry!(
+                "p2p_peer_disconnects",
+                "Total P2P peer connections lost, by direction",
+                &["direction"],
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    pub fn on_connect(&self, direction: ConnectionDirection) {
+        self.peer_connects
+            .with_label_values(&[direction.as_str()])
+            .inc();
+    }
+
+    pub fn on_disconnect(&self, direction: ConnectionDirection) {
+        self.peer_disconnects
+            .with_label_values(&[direction.as_str()])
+            .inc();
+    }
+
+    /// Update the churn counters from the peer events of `network` until it shuts down.
+    #[must_use]
+    pub fn spawn_monitor(self, network: anemo::NetworkRef) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut subscriber = match network.upgrade() {
+                Some(network) => network.subscribe().0,
+                None => return,
+            };
+            // A lost peer can no longer be looked up, so remember the direction of each
+            // connection until it goes away.
+            let mut directions = HashMap::new();
+            while let Ok(event) = subscriber.recv().await {
+                match event {
+                    anemo::types::PeerEvent::NewPeer(peer_id) => {
+                        let direction = network
+                            .upgrade()
+                            .and_then(|network| network.peer(peer_id))
+                            .map(|peer| ConnectionDirection::from(peer.connection_origin()));
+                        if let Some(direction) = direction {
+                            directions.insert(peer_id, direction);
+                            self.on_connect(direction);
+                        }
+                    }
+                    anemo::types::PeerEvent::LostPeer(peer_id, _) => {
+                        if let Some(direction) = directions.remove(&peer_id) {
+                            self.on_disconnect(direction);
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_churn_metrics() {
+        let registry = Registry::new();
+        let metrics = ConnectionChurnMetrics::new(&registry);
+
+        metrics.on_connect(ConnectionDirection::Inbound);
+        metrics.on_connect(ConnectionDirection::Inbound);
+        metrics.on_connect(ConnectionDirection::Outbound);
+        metrics.on_disconnect(ConnectionDirection::Inbound);
+
+        let count = |counter: &IntCounterVec, direction: ConnectionDirection| {
+            counter.with_label_values(&[direction.as_str()]).get()
+        };
+        assert_eq!(
+            count(&metrics.peer_connects, ConnectionDirection::Inbound),
+            2
+        );
+        assert_eq!(
+            count(&metrics.peer_connects, ConnectionDirection::Outbound),
+            1
+        );
+        assert_eq!(
+            count(&metrics.peer_disconnects, ConnectionDirection::Inbound),
+            1
+        );
+        assert_eq!(
+            count(&metrics.peer_disconnects, ConnectionDirection::Outbound),
+            0
+        );
+
+        // The counters are exported through the registry.
+        let names: Vec<_> = registry
+            .gather()
+            .iter()
+            .map(|family| family.get_name().to_string())
+            .collect();
+        assert!(names.contains(&"p2p_peer_connects".to_string()));
+        assert!(names.contains(&"p2p_peer_disconnects".to_string()));
+    }
+}
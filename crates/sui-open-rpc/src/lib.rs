@@ -105,6 +105,13 @@ pub struct Module {
     components: Components,
 }
 
+impl Module {
+    /// The namespaced names of the methods in this module, e.g. `sui_getObject`.
+    pub fn method_names(&self) -> impl Iterator<Item = &str> {
+        self.methods.iter().map(|method| method.name.as_str())
+    }
+}
+
 pub struct RpcModuleDocBuilder {
     schema_generator: SchemaGenerator,
     methods: BTreeMap<String, Method>,
@@ -48,7 +48,7 @@ pub async fn status(
         .get_object_read(&SUI_SYSTEM_STATE_OBJECT_ID)
         .await?;
 
-    let system_state: SuiSystemState = bcs::from_bytes(
+    let system_state = SuiSystemState::from_bcs_bytes(
         object
             .into_object()?
             .data
@@ -334,6 +334,20 @@ fn parse_operations(
             metadata: Some(json!(change)),
         }],
         SingleTransactionKind::Pay(pay) => parse_pay(sender, gas, budget, pay, counter, status),
+        SingleTransactionKind::ConsensusCommitPrologue(_) => vec![],
+        SingleTransactionKind::Upgrade(u) => {
+            let disassembled = disassemble_modules(u.modules.iter())?;
+            vec![Operation {
+                operation_identifier: counter.next_idx().into(),
+                related_operations: vec![],
+                type_: OperationType::Upgrade,
+                status,
+                account: Some(AccountIdentifier { address: sender }),
+                amount: None,
+                coin_change: None,
+                metadata: Some(json!(disassembled)),
+            }]
+        }
     };
     if let Some(effects) = effects {
         let coin_change_operations = Operation::get_coin_operation_from_events(
@@ -658,6 +672,7 @@ impl TryInto<SuiAction> for Vec<Operation> {
                 | OperationType::Genesis
                 | OperationType::MoveCall
                 | OperationType::Publish
+                | OperationType::Upgrade
                 | OperationType::EpochChange => return Err(Error::unsupported_operation(op.type_)),
             }
         }
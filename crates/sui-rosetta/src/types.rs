@@ -409,6 +409,7 @@ pub enum OperationType {
     Pay,
     TransferObject,
     Publish,
+    Upgrade,
     MoveCall,
     EpochChange,
     Genesis,
@@ -419,7 +419,8 @@ impl QuorumDriver {
                         parsed_data: None,
                     },
                     (
-                        ExecuteTransactionRequestType::WaitForEffectsCert,
+                        ExecuteTransactionRequestType::WaitForEffectsCert
+                        | ExecuteTransactionRequestType::BestEffortWithPartialEffects,
                         SuiExecuteTransactionResponse::EffectsCert {
                             certificate,
                             effects,
@@ -454,6 +455,17 @@ impl QuorumDriver {
                             parsed_data: None,
                         }
                     }
+                    (
+                        ExecuteTransactionRequestType::BestEffortWithPartialEffects,
+                        SuiExecuteTransactionResponse::PartialEffects { effects },
+                    ) => TransactionExecutionResult {
+                        tx_digest: effects.transaction_digest,
+                        tx_cert: None,
+                        effects: Some(effects),
+                        confirmed_local_execution: false,
+                        timestamp_ms: None,
+                        parsed_data: None,
+                    },
                     (other_request_type, other_resp) => {
                         bail!(
                             "Invalid response type {:?} for request type: {:?}",
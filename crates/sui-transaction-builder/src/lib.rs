@@ -18,7 +18,7 @@ use sui_json_rpc_types::GetRawObjectDataResponse;
 use sui_json_rpc_types::SuiObjectInfo;
 use sui_json_rpc_types::{RPCTransactionRequestParams, SuiData, SuiTypeTag};
 use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
-use sui_types::error::SuiError;
+use sui_types::error::{BatchValidationError, SuiError};
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
     CallArg, InputObjectKind, MoveCall, ObjectArg, SingleTransactionKind, TransactionData,
@@ -298,12 +298,12 @@ impl TransactionBuilder {
         gas_budget: u64,
     ) -> anyhow::Result<TransactionData> {
         let gas = self.select_gas(sender, gas, gas_budget, vec![]).await?;
-        Ok(TransactionData::new_module(
+        Ok(TransactionData::try_new_module(
             sender,
             gas,
             compiled_modules,
             gas_budget,
-        ))
+        )?)
     }
 
     pub async fn split_coin(
@@ -410,7 +410,7 @@ impl TransactionBuilder {
         fp_ensure!(
             !single_transaction_params.is_empty(),
             SuiError::InvalidBatchTransaction {
-                error: "Batch Transaction cannot be empty".to_owned(),
+                error: BatchValidationError::Empty,
             }
             .into()
         );
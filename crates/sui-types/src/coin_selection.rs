@@ -0,0 +1,54 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::base_types::ObjectRef;
+use crate::error::{SuiError, SuiResult};
+
+#[cfg(test)]
+#[path = "unit_tests/coin_selection_tests.rs"]
+mod coin_selection_tests;
+
+/// Select coins from `coins`, given as pairs of coin reference and balance, whose balances
+/// add up to at least `target` plus `gas_budget`. Coins are picked greedily, largest balance
+/// first, which keeps the number of selected coins small. Fails with `InsufficientBalance`
+/// if all the coins together are not enough.
+pub fn select_coins(
+    coins: &[(ObjectRef, u64)],
+    target: u64,
+    gas_budget: u64,
+) -> SuiResult<Vec<ObjectRef>> {
+    let required = target as u128 + gas_budget as u128;
+    let mut sorted: Vec<_> = coins.iter().collect();
+    sorted.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let mut selected = Vec::new();
+    let mut total: u128 = 0;
+    for (coin, balance) in sorted {
+        if total >= required {
+            break;
+        }
+        selected.push(*coin);
+        total += *balance as u128;
+    }
+
+    if total < required {
+        return Err(SuiError::InsufficientBalance {
+            balance: total.try_into().unwrap_or(u64::MAX),
+            required: required.try_into().unwrap_or(u64::MAX),
+        });
+    }
+    Ok(selected)
+}
+
+/// The total balance of the coins among `object_ids`, e.g. the objects listed in an
+/// `AccountInfoResponse`. `resolver` returns the balance of an object if it is a coin and
+/// None otherwise; objects that are not coins are ignored. The sum saturates at `u64::MAX`.
+pub fn account_balance<F>(object_ids: &[ObjectRef], resolver: F) -> u64
+where
+    F: Fn(&ObjectRef) -> Option<u64>,
+{
+    object_ids
+        .iter()
+        .filter_map(resolver)
+        .fold(0, u64::saturating_add)
+}
@@ -253,6 +253,12 @@ impl Committee {
         self.voting_rights.iter().map(|(name, _)| name)
     }
 
+    /// The members of the committee in index order, i.e. sorted by name. This is the order
+    /// signer bitmaps refer to, so it is the same on every node for a given committee.
+    pub fn ordered_names(&self) -> Vec<AuthorityName> {
+        self.names().copied().collect()
+    }
+
     pub fn stakes(&self) -> impl Iterator<Item = StakeUnit> + '_ {
         self.voting_rights.iter().map(|(_, stake)| *stake)
     }
@@ -295,6 +301,45 @@ impl Display for Committee {
     }
 }
 
+/// Membership changes between two committees, e.g. across a reconfiguration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommitteeDiff {
+    /// Authorities only present in the new committee, with their stake.
+    pub added: Vec<(AuthorityName, StakeUnit)>,
+    /// Authorities only present in the old committee, with their stake.
+    pub removed: Vec<(AuthorityName, StakeUnit)>,
+    /// Authorities present in both committees whose stake changed, as (name, old, new).
+    pub stake_changed: Vec<(AuthorityName, StakeUnit, StakeUnit)>,
+}
+
+impl CommitteeDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.stake_changed.is_empty()
+    }
+}
+
+/// Compares the members of `old` and `new`. Results are ordered by authority name.
+pub fn committee_diff(old: &Committee, new: &Committee) -> CommitteeDiff {
+    let mut diff = CommitteeDiff::default();
+    for (name, old_stake) in old.members() {
+        match new.voting_rights.binary_search_by_key(name, |(a, _)| *a) {
+            Err(_) => diff.removed.push((*name, *old_stake)),
+            Ok(idx) => {
+                let new_stake = new.voting_rights[idx].1;
+                if new_stake != *old_stake {
+                    diff.stake_changed.push((*name, *old_stake, new_stake));
+                }
+            }
+        }
+    }
+    diff.added = new
+        .members()
+        .filter(|(name, _)| !old.authority_exists(name))
+        .cloned()
+        .collect();
+    diff
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CommitteeWithNetAddresses {
     pub committee: Committee,
@@ -391,4 +436,31 @@ mod test {
             (a3, "c")
         );
     }
+
+    #[test]
+    fn test_committee_diff() {
+        let names: Vec<AuthorityName> = (0..4)
+            .map(|_| {
+                let (_, sec): (_, AuthorityKeyPair) = get_key_pair();
+                sec.public().into()
+            })
+            .collect();
+        let (a1, a2, a3, a4) = (names[0], names[1], names[2], names[3]);
+
+        let old = Committee::new(0, BTreeMap::from([(a1, 1), (a2, 1), (a3, 1)])).unwrap();
+        assert!(committee_diff(&old, &old).is_empty());
+
+        // a4 joins, a2 leaves and a3 changes its stake.
+        let new = Committee::new(1, BTreeMap::from([(a1, 1), (a3, 5), (a4, 2)])).unwrap();
+        let diff = committee_diff(&old, &new);
+        assert_eq!(diff.added, vec![(a4, 2)]);
+        assert_eq!(diff.removed, vec![(a2, 1)]);
+        assert_eq!(diff.stake_changed, vec![(a3, 1, 5)]);
+
+        // The diff in the other direction is symmetric.
+        let reverse = committee_diff(&new, &old);
+        assert_eq!(reverse.added, diff.removed);
+        assert_eq!(reverse.removed, diff.added);
+        assert_eq!(reverse.stake_changed, vec![(a3, 5, 1)]);
+    }
 }
@@ -47,6 +47,23 @@ const VALIDATOR_HALTED_ERROR_MSG: &str =
     "Validator temporarily stopped processing transactions due to epoch change";
 const MISSING_COMMITTEE_ERROR_MSG: &str = "Missing committee information for epoch";
 
+/// Why a batch transaction was rejected. Indices refer to positions in the batch.
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Error, Hash)]
+pub enum BatchValidationError {
+    #[error("Batch Transaction cannot be empty")]
+    Empty,
+    #[error("Transaction {0} of the batch cannot be batched. Only Call, TransferObject and Pay are allowed")]
+    ContainsNonBatchable(usize),
+    #[error(
+        "Transaction {0} of the batch is a system transaction, which cannot be part of a batch"
+    )]
+    ContainsSystemTx(usize),
+    #[error("Batch of {size} transactions exceeds the maximum of {max}")]
+    TooLarge { size: usize, max: usize },
+    #[error("Mutable object {0} cannot appear in more than one transaction of a batch")]
+    DuplicateMutableObject(ObjectID),
+}
+
 /// Custom error type for Sui.
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, Error, Hash)]
 #[allow(clippy::large_enum_variant)]
@@ -62,6 +79,8 @@ pub enum SuiError {
     MoveObjectAsPackage { object_id: ObjectID },
     #[error("The SUI coin to be transferred has balance {balance}, which is not enough to cover the transfer amount {required}")]
     TransferInsufficientBalance { balance: u64, required: u64 },
+    #[error("The selected coins have a total balance of {balance}, which is not enough to cover the required amount {required}")]
+    InsufficientBalance { balance: u64, required: u64 },
     #[error("A move object is expected, instead a move package is passed: {object_id}")]
     MovePackageAsObject { object_id: ObjectID },
     #[error("Expecting a singler owner, shared ownership found")]
@@ -75,7 +94,30 @@ pub enum SuiError {
     #[error("The shared locks for this transaction have not yet been set.")]
     SharedObjectLockNotSetError,
     #[error("Invalid Batch Transaction: {}", error)]
-    InvalidBatchTransaction { error: String },
+    InvalidBatchTransaction { error: BatchValidationError },
+    #[error("Expected {expected} command labels, got {actual}")]
+    CommandLabelCountMismatch { expected: usize, actual: usize },
+    #[error("Invalid Transaction Intent: {}", error)]
+    InvalidTransactionIntent { error: String },
+    #[error("Transaction depends on {count} transactions, the maximum is {max}")]
+    TooManyTransactionDependencies { count: usize, max: usize },
+    #[error("Transaction requires transaction version {required}, but only version {enabled} is enabled")]
+    UnsupportedTransactionVersion { required: u64, enabled: u64 },
+    #[error("Failed to deserialize transaction: {}", error)]
+    TransactionDeserializationError { error: String },
+    #[error("Failed to deserialize transaction effects: {}", error)]
+    TransactionEffectsDeserializationError { error: String },
+    #[error(
+        "Object {object_id:?} is received by the transaction, but it is not owned by an object"
+    )]
+    ReceivingObjectNotObjectOwned { object_id: ObjectID },
+    #[error(
+        "Object {object_id:?} is received by the transaction, but its owner {owner:?} is not passed to the same call"
+    )]
+    ReceivingObjectOwnerMismatch {
+        object_id: ObjectID,
+        owner: ObjectID,
+    },
     #[error("Object {child_id:?} is owned by object {parent_id:?}, which is not in the input")]
     MissingObjectOwner {
         child_id: ObjectID,
@@ -85,6 +127,8 @@ pub enum SuiError {
     // Signature verification
     #[error("Signature is not valid: {}", error)]
     InvalidSignature { error: String },
+    #[error("Signature scheme {scheme} is not allowed")]
+    SignatureSchemeNotAllowed { scheme: String },
     #[error("Sender Signature must be verified separately from Authority Signature")]
     SenderSigUnbatchable,
     #[error("Value was not signed by the correct sender: {}", error)]
@@ -175,6 +219,11 @@ pub enum SuiError {
     InvalidAddress,
     #[error("Invalid transaction digest.")]
     InvalidTransactionDigest,
+    #[error("Certificate for transaction {certificate_digest:?} is paired with the effects of transaction {effects_digest:?}.")]
+    CertificateEffectsMismatch {
+        certificate_digest: TransactionDigest,
+        effects_digest: TransactionDigest,
+    },
     #[error(
         "Invalid Object digest for object {object_id:?}. Expected digest : {expected_digest:?}."
     )]
@@ -269,6 +318,8 @@ pub enum SuiError {
     GasBudgetTooHigh { error: String },
     #[error("Insufficient gas: {error:?}.")]
     InsufficientGas { error: String },
+    #[error("Gas object {object_id} is also used as an input of the transaction")]
+    GasObjectInInputs { object_id: ObjectID },
 
     // Internal state errors
     #[error("Attempt to update state of TxContext from a different instance than original.")]
@@ -292,6 +343,8 @@ pub enum SuiError {
     },
     #[error("{TRANSACTION_NOT_FOUND_MSG_PREFIX} [{:?}].", digest)]
     TransactionNotFound { digest: TransactionDigest },
+    #[error("Certificate {:?} was not executed.", digest)]
+    CertificateNotExecuted { digest: TransactionDigest },
     #[error("Could not find the referenced object {:?}.", object_id)]
     ObjectNotFound { object_id: ObjectID },
     #[error(
@@ -381,6 +434,10 @@ pub enum SuiError {
     ConcurrentTransactionError,
     #[error("Transfer should be received by us.")]
     IncorrectRecipientError,
+    #[error("Pay lists recipient {recipient} more than once.")]
+    DuplicatePayRecipient { recipient: SuiAddress },
+    #[error("Invalid Pay transaction: {}", error)]
+    InvalidPayTransaction { error: String },
     #[error("Too many authority errors were detected for {}: {:?}", action, errors)]
     TooManyIncorrectAuthorities {
         errors: Vec<(AuthorityName, SuiError)>,
@@ -436,6 +493,8 @@ pub enum SuiError {
 
     #[error("Use of disabled feature: {:?}", error)]
     UnsupportedFeatureError { error: String },
+    #[error("This endpoint is not available on a {role} node")]
+    EndpointNotAvailableOnRole { role: &'static str },
 
     #[error("Unable to communicate with the Quorum Driver channel: {:?}", error)]
     QuorumDriverCommunicationError { error: String },
@@ -552,6 +611,12 @@ impl ExecutionError {
     pub fn to_execution_status(&self) -> ExecutionFailureStatus {
         self.kind().clone()
     }
+
+    /// Attributes this error to the command at position `index` of a batch transaction.
+    pub fn in_command(self, index: usize) -> Self {
+        let ExecutionErrorInner { kind, source } = *self.inner;
+        Self::new(ExecutionFailureStatus::in_command(index, kind), source)
+    }
 }
 
 impl std::fmt::Display for ExecutionError {
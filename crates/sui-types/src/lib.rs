@@ -21,6 +21,7 @@ pub mod balance;
 pub mod base_types;
 pub mod batch;
 pub mod coin;
+pub mod coin_selection;
 pub mod committee;
 pub mod crypto;
 pub mod event;
@@ -43,6 +44,7 @@ pub mod temporary_store;
 pub mod waypoint;
 
 pub mod filter;
+#[cfg(any(test, feature = "test-utils"))]
 #[path = "./unit_tests/utils.rs"]
 pub mod utils;
 
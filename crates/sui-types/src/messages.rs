@@ -4,19 +4,19 @@
 use super::{base_types::*, batch::*, committee::Committee, error::*, event::Event};
 use crate::committee::{EpochId, StakeUnit};
 use crate::crypto::{
-    sha3_hash, AuthoritySignInfo, AuthoritySignInfoTrait, AuthoritySignature,
-    AuthorityStrongQuorumSignInfo, Ed25519SuiSignature, EmptySignInfo, Signable, Signature,
-    SignatureScheme, SuiAuthoritySignature, SuiSignature, SuiSignatureInner, ToFromBytes,
-    VerificationObligation,
+    sha3_hash, AuthorityQuorumSignInfo, AuthoritySignInfo, AuthoritySignInfoTrait,
+    AuthoritySignature, AuthorityStrongQuorumSignInfo, AuthorityWeakQuorumSignInfo,
+    Ed25519SuiSignature, EmptySignInfo, Signable, Signature, SignatureScheme,
+    SuiAuthoritySignature, SuiSignature, SuiSignatureInner, ToFromBytes, VerificationObligation,
 };
 use crate::gas::GasCostSummary;
 use crate::messages_checkpoint::{
     AuthenticatedCheckpoint, CheckpointFragment, CheckpointSequenceNumber,
 };
 use crate::object::{Object, ObjectFormatOptions, Owner, OBJECT_START_VERSION};
-use crate::storage::{DeleteKind, WriteKind};
+use crate::storage::{BackingPackageStore, DeleteKind, WriteKind};
 use crate::sui_serde::Base64;
-use crate::SUI_SYSTEM_STATE_OBJECT_ID;
+use crate::{parse_sui_type_tag, SUI_SYSTEM_STATE_OBJECT_ID};
 use base64ct::Encoding;
 use byteorder::{BigEndian, ReadBytesExt};
 use itertools::Either;
@@ -37,6 +37,7 @@ use serde_with::Bytes;
 use std::collections::hash_map::DefaultHasher;
 use std::fmt::Write;
 use std::fmt::{Display, Formatter};
+use std::io::Read;
 use std::{
     collections::{BTreeMap, BTreeSet, HashSet},
     hash::{Hash, Hasher},
@@ -63,6 +64,77 @@ pub enum ObjectArg {
     ImmOrOwnedObject(ObjectRef),
     // A Move object that's shared and mutable.
     SharedObject(ObjectID),
+    // A Move object owned by another object of the call, which the call receives.
+    // Appended last so that the BCS encoding of the other variants is unchanged.
+    Receiving(ObjectRef),
+    // A shared Move object that the call only reads, i.e. takes by immutable reference.
+    // Appended last so that the BCS encoding of the other variants is unchanged.
+    ReadonlySharedObject(ObjectID),
+}
+
+impl ObjectArg {
+    /// The ID of the shared object this argument refers to, paired with whether the object
+    /// may be mutated, or `None` if the argument is not a shared object.
+    pub fn shared_object(&self) -> Option<(&ObjectID, bool)> {
+        match self {
+            ObjectArg::SharedObject(id) => Some((id, true)),
+            ObjectArg::ReadonlySharedObject(id) => Some((id, false)),
+            ObjectArg::ImmOrOwnedObject(_) | ObjectArg::Receiving(_) => None,
+        }
+    }
+
+    /// The lowest transaction format version that can express this argument.
+    pub fn required_version(&self) -> u64 {
+        match self {
+            ObjectArg::Receiving(_) | ObjectArg::ReadonlySharedObject(_) => 2,
+            ObjectArg::ImmOrOwnedObject(_) | ObjectArg::SharedObject(_) => BASE_TRANSACTION_VERSION,
+        }
+    }
+
+    fn structurally_equal(&self, other: &ObjectArg) -> bool {
+        match (self, other) {
+            (ObjectArg::ImmOrOwnedObject(a), ObjectArg::ImmOrOwnedObject(b))
+            | (ObjectArg::Receiving(a), ObjectArg::Receiving(b)) => a.0 == b.0,
+            (a, b) => a == b,
+        }
+    }
+}
+
+impl CallArg {
+    /// The lowest transaction format version that can express this argument.
+    pub fn required_version(&self) -> u64 {
+        match self {
+            CallArg::Pure(_) => BASE_TRANSACTION_VERSION,
+            CallArg::Object(arg) => arg.required_version(),
+            CallArg::ObjVec(args) => args
+                .iter()
+                .map(ObjectArg::required_version)
+                .max()
+                .unwrap_or(BASE_TRANSACTION_VERSION),
+        }
+    }
+
+    fn structurally_equal(&self, other: &CallArg) -> bool {
+        match (self, other) {
+            (CallArg::Object(a), CallArg::Object(b)) => a.structurally_equal(b),
+            (CallArg::ObjVec(a), CallArg::ObjVec(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.structurally_equal(b))
+            }
+            (a, b) => a == b,
+        }
+    }
+
+    /// The canonical encoding of a `CallArg`, which is the reference encoding that all SDKs
+    /// must reproduce to obtain matching transaction digests. It is the BCS encoding of the arg:
+    /// - the variant index as ULEB128 (`Pure` = 0, `Object` = 1, `ObjVec` = 2), followed by
+    /// - for `Pure`, the ULEB128 length of the payload and the payload itself. The payload must
+    ///   be the BCS encoding of the Move value (e.g. a `u64` is 8 little-endian bytes);
+    /// - for `Object`, the BCS encoding of the `ObjectArg`;
+    /// - for `ObjVec`, the ULEB128 number of elements followed by the BCS encoding of each
+    ///   `ObjectArg` in order. No per-element length prefix is used.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("BCS serialization of CallArg cannot fail")
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
@@ -92,6 +164,106 @@ pub struct MoveModulePublish {
     pub modules: Vec<Vec<u8>>,
 }
 
+impl MoveModulePublish {
+    /// Returns the names of the modules being published, skipping any module that
+    /// cannot be deserialized. Use `try_module_names` to surface malformed modules instead.
+    pub fn module_names(&self) -> Vec<String> {
+        self.modules
+            .iter()
+            .filter_map(|bytes| CompiledModule::deserialize(bytes).ok())
+            .map(|module| module.self_id().name().to_string())
+            .collect()
+    }
+
+    /// Like `module_names`, but fails on the first module that cannot be deserialized.
+    pub fn try_module_names(&self) -> SuiResult<Vec<String>> {
+        self.modules
+            .iter()
+            .map(|bytes| {
+                CompiledModule::deserialize(bytes)
+                    .map(|module| module.self_id().name().to_string())
+                    .map_err(|error| SuiError::ModuleDeserializationFailure {
+                        error: error.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Decode a BCS-encoded `MoveModulePublish` from an untrusted reader, enforcing `limits`
+    /// as it goes: the length prefix of every module is checked before the module is read,
+    /// so an oversized payload is rejected without buffering it. The reader is left
+    /// positioned after the last module.
+    pub fn try_from_reader<R: Read>(mut reader: R, limits: PublishLimits) -> SuiResult<Self> {
+        let malformed = |error: String| SuiError::TransactionDeserializationError { error };
+        let count = read_uleb128_from(&mut reader)
+            .ok_or_else(|| malformed("Missing module count".to_string()))?;
+
+        let mut modules = Vec::new();
+        let mut total_size = 0;
+        for _ in 0..count {
+            let size = read_uleb128_from(&mut reader)
+                .ok_or_else(|| malformed("Missing module length".to_string()))?;
+            // Empty modules are never valid, and rejecting them bounds the module count by
+            // the total size.
+            fp_ensure!(size > 0, malformed("Empty module".to_string()));
+            fp_ensure!(
+                size <= limits.max_module_size as u64,
+                malformed(format!(
+                    "Module of {size} bytes exceeds the maximum of {}",
+                    limits.max_module_size
+                ))
+            );
+            let size = size as usize;
+            total_size += size;
+            fp_ensure!(
+                total_size <= limits.max_total_size,
+                malformed(format!(
+                    "Modules exceed the maximum total size of {} bytes",
+                    limits.max_total_size
+                ))
+            );
+            let mut module = vec![0; size];
+            reader
+                .read_exact(&mut module)
+                .map_err(|e| malformed(e.to_string()))?;
+            modules.push(module);
+        }
+        Ok(Self { modules })
+    }
+}
+
+/// Size limits enforced by `MoveModulePublish::try_from_reader` when decoding untrusted bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublishLimits {
+    /// The maximum size in bytes of a single module.
+    pub max_module_size: usize,
+    /// The maximum combined size in bytes of all modules.
+    pub max_total_size: usize,
+}
+
+impl Default for PublishLimits {
+    fn default() -> Self {
+        Self {
+            max_module_size: 64 * 1024,
+            max_total_size: 512 * 1024,
+        }
+    }
+}
+
+/// Replace the modules of an existing package. The upgrade is authorized by the `ticket`
+/// object, which must be owned by the sender.
+#[serde_as]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct MovePackageUpgrade {
+    /// The package being upgraded
+    pub package: ObjectID,
+    /// The new modules of the package
+    #[serde_as(as = "Vec<Bytes>")]
+    pub modules: Vec<Vec<u8>>,
+    /// The object authorizing the upgrade
+    pub ticket: ObjectRef,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct TransferSui {
     pub recipient: SuiAddress,
@@ -120,6 +292,31 @@ pub struct ChangeEpoch {
     pub computation_charge: u64,
 }
 
+impl Pay {
+    /// Whether the same address appears more than once in `recipients`. This is legal, but
+    /// usually means the client failed to merge what should have been a single entry.
+    pub fn has_duplicate_recipients(&self) -> bool {
+        let mut seen = HashSet::new();
+        !self.recipients.iter().all(|r| seen.insert(r))
+    }
+}
+
+impl ChangeEpoch {
+    /// The total amount of gas rewarded for the epoch, i.e. the storage and computation
+    /// charges combined, saturating at `u64::MAX`.
+    pub fn total_gas_reward(&self) -> u64 {
+        self.storage_charge.saturating_add(self.computation_charge)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct ConsensusCommitPrologue {
+    /// The consensus round of the commit.
+    pub round: u64,
+    /// Unix timestamp of the consensus commit, in milliseconds.
+    pub commit_timestamp_ms: u64,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub enum SingleTransactionKind {
     /// Initiate an object transfer between addresses
@@ -141,37 +338,111 @@ pub enum SingleTransactionKind {
     /// A validator will not sign a transaction of this kind from outside. It only
     /// signs internally during epoch changes.
     ChangeEpoch(ChangeEpoch),
+    /// A system transaction that stamps the consensus commit timestamp on-chain.
+    /// Like `ChangeEpoch`, it doesn't require/use a gas object and a validator will not
+    /// sign a transaction of this kind from outside.
+    /// New variants must be appended so that the BCS encoding of existing kinds is unchanged.
+    ConsensusCommitPrologue(ConsensusCommitPrologue),
+    /// Upgrade the modules of a published Move package
+    Upgrade(MovePackageUpgrade),
     // .. more transaction types go here
 }
 
 impl SingleTransactionKind {
+    /// Whether this kind may only be created by validators, e.g. `ChangeEpoch`.
+    pub fn is_system_tx(&self) -> bool {
+        matches!(
+            self,
+            Self::ChangeEpoch(_) | Self::ConsensusCommitPrologue(_)
+        )
+    }
+
+    /// The lowest transaction format version that can express this kind, see
+    /// `MAX_TRANSACTION_VERSION`.
+    pub fn required_version(&self) -> u64 {
+        match self {
+            Self::ConsensusCommitPrologue(_) => 2,
+            // No binary can execute upgrades yet, so no validator can enable their version.
+            Self::Upgrade(_) => MAX_TRANSACTION_VERSION + 1,
+            Self::Call(call) => call
+                .arguments
+                .iter()
+                .map(CallArg::required_version)
+                .max()
+                .unwrap_or(BASE_TRANSACTION_VERSION),
+            Self::TransferObject(_)
+            | Self::Publish(_)
+            | Self::TransferSui(_)
+            | Self::Pay(_)
+            | Self::ChangeEpoch(_) => BASE_TRANSACTION_VERSION,
+        }
+    }
+
+    /// Whether `self` and `other` are the same transaction up to the versions and digests of
+    /// the objects they reference, i.e. whether they express the same intent.
+    pub fn structurally_equal(&self, other: &SingleTransactionKind) -> bool {
+        let same_objects = |a: &[ObjectRef], b: &[ObjectRef]| {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.0 == b.0)
+        };
+        match (self, other) {
+            (Self::TransferObject(a), Self::TransferObject(b)) => {
+                a.recipient == b.recipient && a.object_ref.0 == b.object_ref.0
+            }
+            (Self::Call(a), Self::Call(b)) => {
+                a.package.0 == b.package.0
+                    && a.module == b.module
+                    && a.function == b.function
+                    && a.type_arguments == b.type_arguments
+                    && a.arguments.len() == b.arguments.len()
+                    && a.arguments
+                        .iter()
+                        .zip(&b.arguments)
+                        .all(|(a, b)| a.structurally_equal(b))
+            }
+            (Self::Pay(a), Self::Pay(b)) => {
+                same_objects(&a.coins, &b.coins)
+                    && a.recipients == b.recipients
+                    && a.amounts == b.amounts
+            }
+            (Self::Upgrade(a), Self::Upgrade(b)) => {
+                a.package == b.package && a.modules == b.modules && a.ticket.0 == b.ticket.0
+            }
+            // The remaining kinds reference no object versions.
+            (a, b) => a == b,
+        }
+    }
+
     pub fn contains_shared_object(&self) -> bool {
         self.shared_input_objects().next().is_some()
     }
 
+    /// The shared objects of this transaction, in argument order. Read-only shared objects
+    /// are included: they are sequenced like any other shared object.
     pub fn shared_input_objects(&self) -> impl Iterator<Item = &ObjectID> {
+        self.shared_input_objects_with_mutability()
+            .map(|(id, _)| id)
+    }
+
+    /// Same as `shared_input_objects`, but pairs each object with whether it may be mutated.
+    /// The consensus commit prologue writes the system state, so it is sequenced on that
+    /// object. `ChangeEpoch` is not: it only runs while consensus is off.
+    pub fn shared_input_objects_with_mutability(&self) -> impl Iterator<Item = (&ObjectID, bool)> {
         match &self {
             Self::Call(MoveCall { arguments, .. }) => Either::Left(
                 arguments
                     .iter()
-                    .filter_map(|arg| match arg {
-                        CallArg::Pure(_) | CallArg::Object(ObjectArg::ImmOrOwnedObject(_)) => None,
-                        CallArg::Object(ObjectArg::SharedObject(id)) => Some(vec![id]),
-                        CallArg::ObjVec(vec) => Some(
-                            vec.iter()
-                                .filter_map(|obj_arg| {
-                                    if let ObjectArg::SharedObject(id) = obj_arg {
-                                        Some(id)
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect(),
-                        ),
+                    .flat_map(|arg| match arg {
+                        CallArg::Object(obj_arg) => std::slice::from_ref(obj_arg),
+                        CallArg::ObjVec(vec) => vec.as_slice(),
+                        CallArg::Pure(_) => &[],
                     })
-                    .flatten(),
+                    .filter_map(|obj_arg| obj_arg.shared_object()),
             ),
-            _ => Either::Right(std::iter::empty()),
+            Self::ConsensusCommitPrologue(_) => Either::Right(Either::Left(std::iter::once((
+                &SUI_SYSTEM_STATE_OBJECT_ID,
+                true,
+            )))),
+            _ => Either::Right(Either::Right(std::iter::empty())),
         }
     }
 
@@ -197,21 +468,29 @@ impl SingleTransactionKind {
                 .iter()
                 .filter_map(|arg| match arg {
                     CallArg::Pure(_) => None,
-                    CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref)) => {
+                    CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref))
+                    | CallArg::Object(ObjectArg::Receiving(object_ref)) => {
                         Some(vec![InputObjectKind::ImmOrOwnedMoveObject(*object_ref)])
                     }
                     CallArg::Object(ObjectArg::SharedObject(id)) => {
                         Some(vec![InputObjectKind::SharedMoveObject(*id)])
                     }
+                    CallArg::Object(ObjectArg::ReadonlySharedObject(id)) => {
+                        Some(vec![InputObjectKind::ReadonlySharedMoveObject(*id)])
+                    }
                     CallArg::ObjVec(vec) => Some(
                         vec.iter()
                             .map(|obj_arg| match obj_arg {
-                                ObjectArg::ImmOrOwnedObject(object_ref) => {
+                                ObjectArg::ImmOrOwnedObject(object_ref)
+                                | ObjectArg::Receiving(object_ref) => {
                                     InputObjectKind::ImmOrOwnedMoveObject(*object_ref)
                                 }
                                 ObjectArg::SharedObject(id) => {
                                     InputObjectKind::SharedMoveObject(*id)
                                 }
+                                ObjectArg::ReadonlySharedObject(id) => {
+                                    InputObjectKind::ReadonlySharedMoveObject(*id)
+                                }
                             })
                             .collect(),
                     ),
@@ -240,11 +519,17 @@ impl SingleTransactionKind {
             Self::TransferSui(_) => {
                 vec![]
             }
+            Self::Upgrade(MovePackageUpgrade {
+                package, ticket, ..
+            }) => vec![
+                InputObjectKind::MovePackage(*package),
+                InputObjectKind::ImmOrOwnedMoveObject(*ticket),
+            ],
             Self::Pay(Pay { coins, .. }) => coins
                 .iter()
                 .map(|o| InputObjectKind::ImmOrOwnedMoveObject(*o))
                 .collect(),
-            Self::ChangeEpoch(_) => {
+            Self::ChangeEpoch(_) | Self::ConsensusCommitPrologue(_) => {
                 vec![InputObjectKind::SharedMoveObject(
                     SUI_SYSTEM_STATE_OBJECT_ID,
                 )]
@@ -306,6 +591,14 @@ impl Display for SingleTransactionKind {
             Self::Publish(_p) => {
                 writeln!(writer, "Transaction Kind : Publish")?;
             }
+            Self::Upgrade(u) => {
+                writeln!(writer, "Transaction Kind : Upgrade")?;
+                writeln!(writer, "Package ID : {}", u.package.to_hex_literal())?;
+                let (object_id, seq, digest) = u.ticket;
+                writeln!(writer, "Ticket ID : {}", &object_id)?;
+                writeln!(writer, "Ticket Sequence Number : {:?}", seq)?;
+                writeln!(writer, "Ticket Digest : {}", encode_bytes_hex(digest.0))?;
+            }
             Self::Call(c) => {
                 writeln!(writer, "Transaction Kind : Call")?;
                 writeln!(writer, "Package ID : {}", c.package.0.to_hex_literal())?;
@@ -319,12 +612,74 @@ impl Display for SingleTransactionKind {
                 writeln!(writer, "New epoch ID: {}", e.epoch)?;
                 writeln!(writer, "Storage gas reward: {}", e.storage_charge)?;
                 writeln!(writer, "Computation gas reward: {}", e.computation_charge)?;
+                writeln!(writer, "Total gas reward: {}", e.total_gas_reward())?;
+            }
+            Self::ConsensusCommitPrologue(p) => {
+                writeln!(writer, "Transaction Kind : Consensus Commit Prologue")?;
+                writeln!(writer, "Round : {}", p.round)?;
+                writeln!(writer, "Commit Timestamp : {}", p.commit_timestamp_ms)?;
             }
         }
         write!(f, "{}", writer)
     }
 }
 
+/// The transaction format version every node accepts.
+pub const BASE_TRANSACTION_VERSION: u64 = 1;
+
+/// The newest transaction format version this binary can execute. Kinds and arguments that
+/// need a version above `BASE_TRANSACTION_VERSION` are only signed once a validator enables
+/// that version, so that they are not certified while part of the committee still runs a
+/// binary that cannot decode them.
+pub const MAX_TRANSACTION_VERSION: u64 = 2;
+
+/// The maximum number of transactions in a `TransactionKind::Batch`.
+pub const MAX_TX_BATCH_SIZE: usize = 1024;
+
+/// Size limits enforced by `TransactionKind::from_bytes_bounded` when decoding untrusted bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// The maximum number of transactions in a `TransactionKind::Batch`.
+    pub max_batch_size: usize,
+    /// The maximum length in bytes of a `CallArg::Pure` argument.
+    pub max_pure_arg_length: usize,
+}
+
+impl Default for DeserializeLimits {
+    fn default() -> Self {
+        Self {
+            max_batch_size: MAX_TX_BATCH_SIZE,
+            max_pure_arg_length: 16 * 1024,
+        }
+    }
+}
+
+/// Read a ULEB128-encoded integer from the front of `bytes`, returning the value and the
+/// number of bytes consumed.
+fn read_uleb128(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    for (i, byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// Like `read_uleb128`, but reads the integer byte by byte from `reader`.
+fn read_uleb128_from<R: Read>(reader: &mut R) -> Option<u64> {
+    let mut value: u64 = 0;
+    for i in 0..10 {
+        let byte = reader.read_u8().ok()?;
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
 // TODO: Make SingleTransactionKind a Box
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize, NamedVariant)]
@@ -351,6 +706,14 @@ impl TransactionKind {
         }
     }
 
+    /// The lowest transaction format version that can express every transaction in `self`.
+    pub fn required_version(&self) -> u64 {
+        self.single_transactions()
+            .map(SingleTransactionKind::required_version)
+            .max()
+            .unwrap_or(BASE_TRANSACTION_VERSION)
+    }
+
     pub fn input_objects(&self) -> SuiResult<Vec<InputObjectKind>> {
         let inputs: Vec<_> = self
             .single_transactions()
@@ -371,6 +734,19 @@ impl TransactionKind {
         }
     }
 
+    /// Whether `self` and `other` are the same transaction up to the versions and digests of
+    /// the objects they reference. Clients can use this to detect a resubmission of the same
+    /// intent after the objects involved have been bumped to a new version.
+    pub fn structurally_equal(&self, other: &TransactionKind) -> bool {
+        match (self, other) {
+            (TransactionKind::Single(a), TransactionKind::Single(b)) => a.structurally_equal(b),
+            (TransactionKind::Batch(a), TransactionKind::Batch(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.structurally_equal(b))
+            }
+            _ => false,
+        }
+    }
+
     pub fn batch_size(&self) -> usize {
         match self {
             TransactionKind::Single(_) => 1,
@@ -378,11 +754,58 @@ impl TransactionKind {
         }
     }
 
+    /// Decode a BCS-encoded `TransactionKind` from untrusted bytes. The length prefix of a
+    /// batch is checked against `limits` before anything is decoded, so an oversized batch
+    /// header is rejected without allocating. Pure arguments cannot be larger than the input
+    /// itself and are checked once decoded, as is the transaction version, so kinds this
+    /// binary cannot execute are refused up front.
+    pub fn from_bytes_bounded(bytes: &[u8], limits: DeserializeLimits) -> SuiResult<Self> {
+        let malformed = |error: String| SuiError::TransactionDeserializationError { error };
+        let (variant, offset) =
+            read_uleb128(bytes).ok_or_else(|| malformed("Missing variant index".to_string()))?;
+        // `Batch` is the second variant of `TransactionKind`.
+        if variant == 1 {
+            let (len, _) = read_uleb128(&bytes[offset..])
+                .ok_or_else(|| malformed("Missing batch length".to_string()))?;
+            fp_ensure!(
+                len <= limits.max_batch_size as u64,
+                malformed(format!(
+                    "Batch of {len} transactions exceeds the maximum of {}",
+                    limits.max_batch_size
+                ))
+            );
+        }
+
+        let kind: Self = bcs::from_bytes(bytes).map_err(|e| malformed(e.to_string()))?;
+        let required = kind.required_version();
+        fp_ensure!(
+            required <= MAX_TRANSACTION_VERSION,
+            SuiError::UnsupportedTransactionVersion {
+                required,
+                enabled: MAX_TRANSACTION_VERSION,
+            }
+        );
+        for single in kind.single_transactions() {
+            if let SingleTransactionKind::Call(call) = single {
+                for arg in &call.arguments {
+                    if let CallArg::Pure(bytes) = arg {
+                        fp_ensure!(
+                            bytes.len() <= limits.max_pure_arg_length,
+                            malformed(format!(
+                                "Pure argument of {} bytes exceeds the maximum of {}",
+                                bytes.len(),
+                                limits.max_pure_arg_length
+                            ))
+                        );
+                    }
+                }
+            }
+        }
+        Ok(kind)
+    }
+
     pub fn is_system_tx(&self) -> bool {
-        matches!(
-            self,
-            TransactionKind::Single(SingleTransactionKind::ChangeEpoch(_))
-        )
+        matches!(self, TransactionKind::Single(s) if s.is_system_tx())
     }
 
     pub fn is_change_epoch_tx(&self) -> bool {
@@ -395,27 +818,35 @@ impl TransactionKind {
     pub fn validity_check(&self) -> SuiResult {
         match self {
             Self::Batch(b) => {
+                let invalid = |error| SuiError::InvalidBatchTransaction { error };
+                fp_ensure!(!b.is_empty(), invalid(BatchValidationError::Empty));
                 fp_ensure!(
-                    !b.is_empty(),
-                    SuiError::InvalidBatchTransaction {
-                        error: "Batch Transaction cannot be empty".to_string(),
-                    }
+                    b.len() <= MAX_TX_BATCH_SIZE,
+                    invalid(BatchValidationError::TooLarge {
+                        size: b.len(),
+                        max: MAX_TX_BATCH_SIZE,
+                    })
                 );
+                // System transactions are executed on their own; one showing up in a batch
+                // is a protocol violation rather than a malformed user batch, so it is
+                // reported as such even if other transactions cannot be batched either.
+                if let Some(index) = b.iter().position(|s| s.is_system_tx()) {
+                    return Err(invalid(BatchValidationError::ContainsSystemTx(index)));
+                }
                 // Check that all transaction kinds can be in a batch.
-                let valid = self.single_transactions().all(|s| match s {
+                let non_batchable = b.iter().position(|s| match s {
                     SingleTransactionKind::Call(_)
                     | SingleTransactionKind::TransferObject(_)
-                    | SingleTransactionKind::Pay(_) => true,
+                    | SingleTransactionKind::Pay(_) => false,
                     SingleTransactionKind::TransferSui(_)
                     | SingleTransactionKind::ChangeEpoch(_)
-                    | SingleTransactionKind::Publish(_) => false,
+                    | SingleTransactionKind::ConsensusCommitPrologue(_)
+                    | SingleTransactionKind::Publish(_)
+                    | SingleTransactionKind::Upgrade(_) => true,
                 });
-                fp_ensure!(
-                    valid,
-                    SuiError::InvalidBatchTransaction {
-                        error: "Batch transaction contains non-batchable transactions. Only Call and TransferObject are allowed".to_string()
-                    }
-                );
+                if let Some(index) = non_batchable {
+                    return Err(invalid(BatchValidationError::ContainsNonBatchable(index)));
+                }
             }
             Self::Single(s) => match s {
                 SingleTransactionKind::Pay(_)
@@ -423,7 +854,9 @@ impl TransactionKind {
                 | SingleTransactionKind::Publish(_)
                 | SingleTransactionKind::TransferObject(_)
                 | SingleTransactionKind::TransferSui(_)
-                | SingleTransactionKind::ChangeEpoch(_) => (),
+                | SingleTransactionKind::ChangeEpoch(_)
+                | SingleTransactionKind::ConsensusCommitPrologue(_)
+                | SingleTransactionKind::Upgrade(_) => (),
             },
         }
         Ok(())
@@ -449,6 +882,111 @@ impl Display for TransactionKind {
     }
 }
 
+/// A JSON-friendly description of a transaction, e.g. as built by wallet backends. Its
+/// encoding is independent of BCS; module, function and type names are plain strings that
+/// are only validated when converted with `TransactionData::from_intent`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct TransactionIntent {
+    pub sender: SuiAddress,
+    pub gas_payment: ObjectRef,
+    pub gas_budget: u64,
+    #[serde(flatten)]
+    pub kind: TransactionIntentKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum TransactionIntentKind {
+    TransferObject {
+        recipient: SuiAddress,
+        object_ref: ObjectRef,
+    },
+    TransferSui {
+        recipient: SuiAddress,
+        amount: Option<u64>,
+    },
+    Pay {
+        coins: Vec<ObjectRef>,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<u64>,
+    },
+    MoveCall {
+        package: ObjectRef,
+        module: String,
+        function: String,
+        #[serde(default)]
+        type_arguments: Vec<String>,
+        #[serde(default)]
+        arguments: Vec<CallArg>,
+    },
+}
+
+impl TransactionIntentKind {
+    fn into_single_transaction_kind(self) -> SuiResult<SingleTransactionKind> {
+        let invalid = |error: String| SuiError::InvalidTransactionIntent { error };
+        Ok(match self {
+            Self::TransferObject {
+                recipient,
+                object_ref,
+            } => SingleTransactionKind::TransferObject(TransferObject {
+                recipient,
+                object_ref,
+            }),
+            Self::TransferSui { recipient, amount } => {
+                SingleTransactionKind::TransferSui(TransferSui { recipient, amount })
+            }
+            Self::Pay {
+                coins,
+                recipients,
+                amounts,
+            } => {
+                fp_ensure!(
+                    !coins.is_empty(),
+                    invalid("Pay requires at least one input coin".to_string())
+                );
+                fp_ensure!(
+                    !recipients.is_empty(),
+                    invalid("Pay requires at least one recipient".to_string())
+                );
+                fp_ensure!(
+                    recipients.len() == amounts.len(),
+                    invalid(format!(
+                        "Pay has {} recipients but {} amounts",
+                        recipients.len(),
+                        amounts.len()
+                    ))
+                );
+                SingleTransactionKind::Pay(Pay {
+                    coins,
+                    recipients,
+                    amounts,
+                })
+            }
+            Self::MoveCall {
+                package,
+                module,
+                function,
+                type_arguments,
+                arguments,
+            } => SingleTransactionKind::Call(MoveCall {
+                package,
+                module: Identifier::new(module)
+                    .map_err(|e| invalid(format!("Invalid module name: {e}")))?,
+                function: Identifier::new(function)
+                    .map_err(|e| invalid(format!("Invalid function name: {e}")))?,
+                type_arguments: type_arguments
+                    .iter()
+                    .map(|t| {
+                        parse_sui_type_tag(t)
+                            .map_err(|e| invalid(format!("Invalid type argument {t}: {e}")))
+                    })
+                    .collect::<SuiResult<_>>()?,
+                arguments,
+            }),
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct TransactionData {
     pub kind: TransactionKind,
@@ -475,6 +1013,19 @@ impl TransactionData {
         }
     }
 
+    /// Same as `new`, but runs `kind.validity_check()` first so that an invalid kind, e.g. an
+    /// empty batch or a batch containing a kind that cannot be batched, is rejected here
+    /// rather than when the transaction is submitted.
+    pub fn try_new(
+        kind: TransactionKind,
+        sender: SuiAddress,
+        gas_payment: ObjectRef,
+        gas_budget: u64,
+    ) -> SuiResult<Self> {
+        kind.validity_check()?;
+        Ok(Self::new(kind, sender, gas_payment, gas_budget))
+    }
+
     pub fn new_with_gas_price(
         kind: TransactionKind,
         sender: SuiAddress,
@@ -511,6 +1062,38 @@ impl TransactionData {
         Self::new(kind, sender, gas_payment, gas_budget)
     }
 
+    /// Like `new_move_call`, but names the package by ID only. Packages are immutable, so
+    /// their reference is looked up in `packages` rather than supplied by the caller.
+    /// Fails with `DependentPackageNotFound` if `packages` does not have the package.
+    pub fn new_move_call_by_package_id(
+        sender: SuiAddress,
+        package: ObjectID,
+        packages: &impl BackingPackageStore,
+        module: Identifier,
+        function: Identifier,
+        type_arguments: Vec<TypeTag>,
+        gas_payment: ObjectRef,
+        arguments: Vec<CallArg>,
+        gas_budget: u64,
+    ) -> SuiResult<Self> {
+        let package = packages
+            .get_package(&package)?
+            .ok_or(SuiError::DependentPackageNotFound {
+                package_id: package,
+            })?
+            .compute_object_reference();
+        Ok(Self::new_move_call(
+            sender,
+            package,
+            module,
+            function,
+            type_arguments,
+            gas_payment,
+            arguments,
+            gas_budget,
+        ))
+    }
+
     pub fn new_transfer(
         recipient: SuiAddress,
         object_ref: ObjectRef,
@@ -555,6 +1138,31 @@ impl TransactionData {
         Self::new(kind, sender, gas_payment, gas_budget)
     }
 
+    /// Same as `new_pay`, but rejects a recipient list that names the same address twice.
+    pub fn try_new_pay(
+        sender: SuiAddress,
+        coins: Vec<ObjectRef>,
+        recipients: Vec<SuiAddress>,
+        amounts: Vec<u64>,
+        gas_payment: ObjectRef,
+        gas_budget: u64,
+    ) -> SuiResult<Self> {
+        let mut seen = HashSet::new();
+        if let Some(recipient) = recipients.iter().find(|r| !seen.insert(*r)) {
+            return Err(SuiError::DuplicatePayRecipient {
+                recipient: *recipient,
+            });
+        }
+        Ok(Self::new_pay(
+            sender,
+            coins,
+            recipients,
+            amounts,
+            gas_payment,
+            gas_budget,
+        ))
+    }
+
     pub fn new_module(
         sender: SuiAddress,
         gas_payment: ObjectRef,
@@ -567,6 +1175,51 @@ impl TransactionData {
         Self::new(kind, sender, gas_payment, gas_budget)
     }
 
+    /// Same as `new_module`, but rejects an empty module list up front instead of letting
+    /// the publish fail at execution with `PublishErrorEmptyPackage`.
+    pub fn try_new_module(
+        sender: SuiAddress,
+        gas_payment: ObjectRef,
+        modules: Vec<Vec<u8>>,
+        gas_budget: u64,
+    ) -> SuiResult<Self> {
+        fp_ensure!(
+            !modules.is_empty(),
+            SuiError::ModulePublishFailure {
+                error: "Publishing an empty package is not allowed".to_string(),
+            }
+        );
+        Ok(Self::new_module(sender, gas_payment, modules, gas_budget))
+    }
+
+    pub fn new_upgrade(
+        sender: SuiAddress,
+        gas_payment: ObjectRef,
+        package: ObjectID,
+        modules: Vec<Vec<u8>>,
+        ticket: ObjectRef,
+        gas_budget: u64,
+    ) -> Self {
+        let kind = TransactionKind::Single(SingleTransactionKind::Upgrade(MovePackageUpgrade {
+            package,
+            modules,
+            ticket,
+        }));
+        Self::new(kind, sender, gas_payment, gas_budget)
+    }
+
+    /// Translates a JSON-friendly `intent` into transaction data, validating the fields that
+    /// are plain strings in the intent.
+    pub fn from_intent(intent: TransactionIntent) -> SuiResult<Self> {
+        let kind = TransactionKind::Single(intent.kind.into_single_transaction_kind()?);
+        Ok(Self::new(
+            kind,
+            intent.sender,
+            intent.gas_payment,
+            intent.gas_budget,
+        ))
+    }
+
     /// Returns the transaction kind as a &str (variant name, no fields)
     pub fn kind_as_str(&self) -> &'static str {
         self.kind.variant_name()
@@ -590,10 +1243,110 @@ impl TransactionData {
         base64ct::Base64::encode_string(&self.to_bytes())
     }
 
+    /// The digest of the transaction data alone, which identifies the intent before it is
+    /// signed. This is not the transaction digest, which also covers the signature; see
+    /// `TransactionEnvelope::digest`.
+    pub fn digest(&self) -> TransactionDigest {
+        TransactionDigest::new(sha3_hash(self))
+    }
+
     pub fn gas_payment_object_ref(&self) -> &ObjectRef {
         &self.gas_payment
     }
 
+    /// Cheap checks that need nothing but the transaction itself, for admission control
+    /// before any object is loaded: the kind passes `validity_check`, every `Pay` has coins
+    /// and one amount per recipient, every `Publish` has modules and none of them is empty,
+    /// and the gas budget is non-zero unless this is a system transaction. Passing does not
+    /// mean the transaction will execute; the semantic checks against the inputs still apply.
+    pub fn syntactic_check(&self) -> SuiResult<()> {
+        self.kind.validity_check()?;
+        for single in self.kind.single_transactions() {
+            match single {
+                SingleTransactionKind::Pay(Pay {
+                    coins,
+                    recipients,
+                    amounts,
+                }) => {
+                    let invalid = |error: String| SuiError::InvalidPayTransaction { error };
+                    fp_ensure!(
+                        !coins.is_empty(),
+                        invalid("Pay requires at least one input coin".to_string())
+                    );
+                    fp_ensure!(
+                        !recipients.is_empty(),
+                        invalid("Pay requires at least one recipient".to_string())
+                    );
+                    fp_ensure!(
+                        recipients.len() == amounts.len(),
+                        invalid(format!(
+                            "Pay has {} recipients but {} amounts",
+                            recipients.len(),
+                            amounts.len()
+                        ))
+                    );
+                }
+                SingleTransactionKind::Publish(MoveModulePublish { modules }) => {
+                    fp_ensure!(
+                        !modules.is_empty() && modules.iter().all(|m| !m.is_empty()),
+                        SuiError::ModulePublishFailure {
+                            error: "Publish requires at least one module, and no empty modules"
+                                .to_string(),
+                        }
+                    );
+                }
+                _ => (),
+            }
+        }
+        fp_ensure!(
+            self.gas_budget > 0 || self.kind.is_system_tx(),
+            SuiError::InsufficientGas {
+                error: "Gas budget must be greater than zero".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    /// Whether this transaction can take the fast path, i.e. be executed without going
+    /// through consensus: it takes no shared objects and is not a system transaction.
+    pub fn is_owned_object_only(&self) -> bool {
+        !self.kind.is_system_tx() && self.kind.shared_input_objects().next().is_none()
+    }
+
+    /// Check that the gas object is not also an owned input of any (sub-)transaction, which
+    /// would use the same object twice. `TransferSui` is exempt, since it is funded by the gas
+    /// object by design.
+    pub fn validate_gas_not_in_inputs(&self) -> SuiResult<()> {
+        let gas_id = self.gas_payment.0;
+        for single in self.kind.single_transactions() {
+            if matches!(single, SingleTransactionKind::TransferSui(_)) {
+                continue;
+            }
+            for input in single.input_objects()? {
+                fp_ensure!(
+                    !matches!(input, InputObjectKind::ImmOrOwnedMoveObject((id, _, _)) if id == gas_id),
+                    SuiError::GasObjectInInputs { object_id: gas_id }
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the declared gas budget does not exceed `max_budget`, a ceiling that a
+    /// deployment may impose to guard against accidental overpayment.
+    pub fn check_gas_budget_ceiling(&self, max_budget: u64) -> SuiResult<()> {
+        fp_ensure!(
+            self.gas_budget <= max_budget,
+            SuiError::GasBudgetTooHigh {
+                error: format!(
+                    "Gas budget {} exceeds the maximum of {max_budget}",
+                    self.gas_budget
+                ),
+            }
+        );
+        Ok(())
+    }
+
     pub fn move_calls(&self) -> Vec<&MoveCall> {
         self.kind
             .single_transactions()
@@ -601,6 +1354,69 @@ impl TransactionData {
             .collect()
     }
 
+    /// Returns the arguments of every move call in this transaction, in call order.
+    pub fn all_call_args(&self) -> Vec<&CallArg> {
+        self.move_calls()
+            .into_iter()
+            .flat_map(|call| &call.arguments)
+            .collect()
+    }
+
+    /// Returns the move call if this transaction is a single `Call`. A batch returns `None`
+    /// even if it holds only one call, since it executes with batch semantics.
+    pub fn single_move_call(&self) -> Option<&MoveCall> {
+        match &self.kind {
+            TransactionKind::Single(s) => s.move_call(),
+            TransactionKind::Batch(_) => None,
+        }
+    }
+
+    /// Returns true if this transaction does nothing but transfer SUI, i.e. it is a single
+    /// `TransferSui`. Fee policies use this to special-case the common payment path.
+    pub fn is_pure_sui_transfer(&self) -> bool {
+        matches!(
+            self.kind,
+            TransactionKind::Single(SingleTransactionKind::TransferSui(_))
+        )
+    }
+
+    /// A lower bound on the number of bytes of new objects this transaction creates.
+    /// Only the module bytes of `Publish` and `Upgrade` are known ahead of execution; every
+    /// other kind contributes 0, even though executing it may well create objects.
+    pub fn min_created_bytes(&self) -> usize {
+        self.kind
+            .single_transactions()
+            .map(|s| match s {
+                SingleTransactionKind::Publish(MoveModulePublish { modules })
+                | SingleTransactionKind::Upgrade(MovePackageUpgrade { modules, .. }) => {
+                    modules.iter().map(|m| m.len()).sum()
+                }
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Every address this transaction transfers objects or pays SUI to, across all the
+    /// single transactions in a batch. Move calls, publishes and upgrades contribute no
+    /// recipients.
+    pub fn recipients(&self) -> BTreeSet<SuiAddress> {
+        self.kind
+            .single_transactions()
+            .flat_map(|s| match s {
+                SingleTransactionKind::TransferObject(TransferObject { recipient, .. })
+                | SingleTransactionKind::TransferSui(TransferSui { recipient, .. }) => {
+                    vec![*recipient]
+                }
+                SingleTransactionKind::Pay(Pay { recipients, .. }) => recipients.clone(),
+                SingleTransactionKind::Call(_)
+                | SingleTransactionKind::Publish(_)
+                | SingleTransactionKind::Upgrade(_)
+                | SingleTransactionKind::ChangeEpoch(_)
+                | SingleTransactionKind::ConsensusCommitPrologue(_) => vec![],
+            })
+            .collect()
+    }
+
     pub fn input_objects(&self) -> SuiResult<Vec<InputObjectKind>> {
         let mut inputs = self.kind.input_objects()?;
 
@@ -611,6 +1427,108 @@ impl TransactionData {
         }
         Ok(inputs)
     }
+
+    /// Whether the system state object is one of the inputs of this transaction, as for
+    /// epoch changes and staking calls. Single transactions with malformed input sets are
+    /// skipped.
+    pub fn touches_system_state(&self) -> bool {
+        self.kind
+            .single_transactions()
+            .filter_map(|s| s.input_objects().ok())
+            .flatten()
+            .any(|kind| kind.object_id() == SUI_SYSTEM_STATE_OBJECT_ID)
+    }
+
+    /// The shared objects of this transaction, in argument order, each paired with whether
+    /// it is used mutably, i.e. `false` for `ObjectArg::ReadonlySharedObject`.
+    pub fn shared_objects_with_mutability(&self) -> Vec<(ObjectID, bool)> {
+        self.kind
+            .single_transactions()
+            .flat_map(|s| s.shared_input_objects_with_mutability())
+            .map(|(id, mutable)| (*id, mutable))
+            .collect()
+    }
+
+    /// Same as `input_objects`, but in a canonical order that does not depend on the order
+    /// of arguments or single transactions: objects sorted by `ObjectID`, followed by
+    /// packages sorted by `ObjectID`. Semantically equal transactions yield the same
+    /// vector, which makes it suitable as a cache key. Duplicate inputs are rejected
+    /// exactly as in `input_objects`.
+    pub fn input_objects_sorted(&self) -> SuiResult<Vec<InputObjectKind>> {
+        let mut inputs = self.input_objects()?;
+        inputs.sort_by_key(|kind| {
+            (
+                matches!(kind, InputObjectKind::MovePackage(_)),
+                kind.object_id(),
+            )
+        });
+        Ok(inputs)
+    }
+}
+
+/// A transaction together with a human-readable label for each of its commands, e.g. for
+/// wallets to show the steps of a batch. The labels are kept on the client, next to the
+/// `TransactionData`, so they are never part of the signed bytes and don't affect the digest.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct LabeledTransaction {
+    pub data: TransactionData,
+    labels: Vec<Option<String>>,
+}
+
+impl LabeledTransaction {
+    /// Fails unless there is exactly one label per command, `None` for unlabeled commands.
+    pub fn new(data: TransactionData, labels: Vec<Option<String>>) -> SuiResult<Self> {
+        fp_ensure!(
+            labels.len() == data.kind.batch_size(),
+            SuiError::CommandLabelCountMismatch {
+                expected: data.kind.batch_size(),
+                actual: labels.len(),
+            }
+        );
+        Ok(Self { data, labels })
+    }
+
+    /// The label of the command at `index`, if it has one.
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.labels.get(index)?.as_deref()
+    }
+
+    /// Pair each command with its label, in execution order.
+    pub fn labeled_commands(&self) -> impl Iterator<Item = (&SingleTransactionKind, Option<&str>)> {
+        self.data
+            .kind
+            .single_transactions()
+            .zip(self.labels.iter().map(|label| label.as_deref()))
+    }
+}
+
+/// The IDs of the `ImmOrOwnedMoveObject` inputs of a transaction, including its gas payment.
+/// Inputs of single transactions with malformed input sets are skipped.
+fn owned_input_object_ids(data: &TransactionData) -> HashSet<ObjectID> {
+    data.kind
+        .single_transactions()
+        .filter_map(|s| s.input_objects().ok())
+        .flatten()
+        .chain(
+            (!data.kind.is_system_tx())
+                .then(|| InputObjectKind::ImmOrOwnedMoveObject(*data.gas_payment_object_ref())),
+        )
+        .filter_map(|kind| match kind {
+            InputObjectKind::ImmOrOwnedMoveObject((id, _, _)) => Some(id),
+            InputObjectKind::MovePackage(_)
+            | InputObjectKind::SharedMoveObject(_)
+            | InputObjectKind::ReadonlySharedMoveObject(_) => None,
+        })
+        .collect()
+}
+
+/// Returns true if `a` and `b` take any of the same `ImmOrOwnedMoveObject` inputs, gas
+/// payment included, so that at most one of them can be executed. Shared objects and
+/// packages are ignored. Objects are compared by ID, regardless of version. Immutable
+/// objects cannot be told apart from owned ones without reading them, so sharing an
+/// immutable input is also reported as a conflict.
+pub fn transactions_conflict(a: &TransactionData, b: &TransactionData) -> bool {
+    !owned_input_object_ids(a).is_disjoint(&owned_input_object_ids(b))
 }
 
 /// A transaction signed by a client, optionally signed by an authority (depending on `S`).
@@ -650,7 +1568,6 @@ pub struct SenderSignedData {
 }
 
 impl<S> TransactionEnvelope<S> {
-    #[allow(dead_code)]
     fn add_sender_sig_to_verification_obligation(
         &self,
         obligation: &mut VerificationObligation,
@@ -686,6 +1603,14 @@ impl<S> TransactionEnvelope<S> {
         self.signed_data.data.gas_payment_object_ref()
     }
 
+    pub fn gas_price(&self) -> u64 {
+        self.signed_data.data.gas_price
+    }
+
+    pub fn gas_budget(&self) -> u64 {
+        self.signed_data.data.gas_budget
+    }
+
     pub fn contains_shared_object(&self) -> bool {
         self.shared_input_objects().next().is_some()
     }
@@ -700,6 +1625,12 @@ impl<S> TransactionEnvelope<S> {
             .get_or_init(|| TransactionDigest::new(sha3_hash(&self.signed_data)))
     }
 
+    /// Compute the transaction digest and write it to the cache without reading it back.
+    /// This is a no-op if the digest has already been computed.
+    pub fn precompute_digest(&self) {
+        self.digest();
+    }
+
     pub fn input_objects_in_compiled_modules(
         compiled_modules: &[CompiledModule],
     ) -> Vec<InputObjectKind> {
@@ -790,6 +1721,19 @@ impl Transaction {
         self.verify_sender_signature()
     }
 
+    /// Same as `verify`, but first checks that the sender signed with one of the `allowed`
+    /// schemes, for deployments that disable some of them.
+    pub fn verify_with_allowed_schemes(&self, allowed: &[SignatureScheme]) -> SuiResult<()> {
+        let scheme = self.signed_data.tx_signature.scheme();
+        fp_ensure!(
+            allowed.iter().any(|s| s.flag() == scheme.flag()),
+            SuiError::SignatureSchemeNotAllowed {
+                scheme: format!("{:?}", scheme),
+            }
+        );
+        self.verify()
+    }
+
     pub fn to_network_data_for_execution(&self) -> (Base64, SignatureScheme, Base64, Base64) {
         (
             Base64::from_bytes(&self.signed_data.data.to_bytes()),
@@ -813,6 +1757,39 @@ impl PartialEq for Transaction {
 }
 impl Eq for Transaction {}
 
+/// Verifies the sender signatures of all `txns` against a single `VerificationObligation`.
+/// System transactions, and transactions already marked as verified, are skipped.
+pub fn verify_sender_signatures(txns: &[Transaction]) -> SuiResult<()> {
+    let mut obligation = VerificationObligation::default();
+    for tx in txns {
+        if tx.is_verified || tx.is_system_tx() {
+            continue;
+        }
+        let idx = obligation.add_message(&tx.signed_data.data);
+        tx.add_sender_sig_to_verification_obligation(&mut obligation, idx)?;
+    }
+
+    // Signatures that cannot be batched are verified as they are added, leaving their
+    // messages without any key to check against.
+    let mut remaining = VerificationObligation::default();
+    for ((message, signature), public_keys) in obligation
+        .messages
+        .into_iter()
+        .zip(obligation.signatures)
+        .zip(obligation.public_keys)
+    {
+        if !public_keys.is_empty() {
+            remaining.messages.push(message);
+            remaining.signatures.push(signature);
+            remaining.public_keys.push(public_keys);
+        }
+    }
+    if remaining.messages.is_empty() {
+        return Ok(());
+    }
+    remaining.verify_all()
+}
+
 /// A transaction that is signed by a sender and also by an authority.
 pub type SignedTransaction = TransactionEnvelope<AuthoritySignInfo>;
 
@@ -889,6 +1866,13 @@ impl SignedTransaction {
         Ok(())
     }
 
+    /// Whether this was signed by a member of `committee` in its epoch. No signature is
+    /// checked, so this is only a cheap pre-filter ahead of `verify`.
+    pub fn is_from_committee(&self, committee: &Committee) -> bool {
+        self.auth_sign_info.epoch == committee.epoch()
+            && committee.authority_exists(&self.auth_sign_info.authority)
+    }
+
     // Turn a SignedTransaction into a Transaction. This is needed when we are
     // forming a CertifiedTransaction, where each transaction's authority signature
     // is taking out to form an aggregated signature.
@@ -913,6 +1897,9 @@ impl PartialEq for SignedTransaction {
 }
 
 pub type CertifiedTransaction = TransactionEnvelope<AuthorityStrongQuorumSignInfo>;
+/// A transaction signed by at least f+1 stake, i.e. by at least one honest authority. This
+/// proves that the transaction was seen by the network, but is not enough to execute it.
+pub type WeakCertifiedTransaction = TransactionEnvelope<AuthorityWeakQuorumSignInfo>;
 pub type TxCertAndSignedEffects = (CertifiedTransaction, SignedTransactionEffects);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
@@ -937,6 +1924,18 @@ pub struct BatchInfoRequest {
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct BatchInfoResponseItem(pub UpdateItem);
 
+impl BatchInfoResponseItem {
+    /// Verify the authority signature on a batch update against `committee`.
+    /// Transaction updates only carry digests, which are authenticated by the batch
+    /// that later includes them, so there is nothing to check for them here.
+    pub fn verify(&self, committee: &Committee) -> SuiResult {
+        match &self.0 {
+            UpdateItem::Batch(signed_batch) => signed_batch.verify(committee),
+            UpdateItem::Transaction(_) => Ok(()),
+        }
+    }
+}
+
 /// Subscribe to notifications when new checkpoint certificates are available.
 ///
 /// Note that there is no start field necessary, because checkpoint sequence numbers are
@@ -1080,6 +2079,18 @@ pub struct TransactionInfoResponse {
     pub signed_effects: Option<SignedTransactionEffects>,
 }
 
+impl TransactionInfoResponse {
+    /// A partial response for servers that only hold the effects of a transaction, and
+    /// neither the signed transaction nor its certificate.
+    pub fn from_effects(effects: SignedTransactionEffects) -> Self {
+        Self {
+            signed_transaction: None,
+            certified_transaction: None,
+            signed_effects: Some(effects),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub enum CallResult {
     Bool(bool),
@@ -1109,7 +2120,7 @@ pub enum ExecutionStatus {
     Failure { error: ExecutionFailureStatus },
 }
 
-#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, NamedVariant)]
 pub enum ExecutionFailureStatus {
     //
     // General transaction errors
@@ -1180,6 +2191,32 @@ pub enum ExecutionFailureStatus {
     MoveAbort(ModuleId, u64), // TODO func def + offset?
     VMVerificationOrDeserializationError,
     VMInvariantViolation,
+
+    //
+    // Batch errors
+    //
+    /// The command at position `index` of a batch transaction failed with `error`
+    InCommand {
+        index: usize,
+        error: Box<ExecutionFailureStatus>,
+    },
+
+    //
+    // Transfer errors (continued)
+    //
+    /// The object to transfer does not have public transfer, i.e. it lacks the `store` ability
+    TransferObjectWithoutPublicTransfer {
+        object: ObjectID,
+    },
+
+    //
+    // General transaction errors (continued)
+    //
+    /// Execution emitted more than `MAX_EVENTS_PER_TX` events
+    TooManyEvents {
+        count: usize,
+        max: usize,
+    },
 }
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize, Hash)]
@@ -1221,6 +2258,12 @@ pub struct InvalidSharedByValue {
 }
 
 impl ExecutionFailureStatus {
+    /// A stable, field-less identifier for the failure (the variant name), suitable for
+    /// structured logs and metrics labels.
+    pub fn error_code(&self) -> &'static str {
+        self.variant_name()
+    }
+
     pub fn entry_argument_error(argument_idx: LocalIndex, kind: EntryArgumentErrorKind) -> Self {
         EntryArgumentError { argument_idx, kind }.into()
     }
@@ -1240,6 +2283,17 @@ impl ExecutionFailureStatus {
     pub fn invalid_shared_by_value(object: ObjectID) -> Self {
         InvalidSharedByValue { object }.into()
     }
+
+    pub fn transfer_object_without_public_transfer(object: ObjectID) -> Self {
+        ExecutionFailureStatus::TransferObjectWithoutPublicTransfer { object }
+    }
+
+    pub fn in_command(index: usize, error: ExecutionFailureStatus) -> Self {
+        ExecutionFailureStatus::InCommand {
+            index,
+            error: Box::new(error),
+        }
+    }
 }
 
 impl Display for ExecutionFailureStatus {
@@ -1372,6 +2426,21 @@ impl Display for ExecutionFailureStatus {
             ExecutionFailureStatus::VMInvariantViolation => {
                 write!(f, "MOVE VM INVARIANT VIOLATION.")
             }
+            ExecutionFailureStatus::InCommand { index, error } => {
+                write!(f, "Command {index} of the batch failed. {error}")
+            }
+            ExecutionFailureStatus::TransferObjectWithoutPublicTransfer { object } => write!(
+                f,
+                "Invalid Transfer Object Transaction. Object {object} does not have public \
+                transfer, so it can only be transferred by a function of the module defining \
+                its type."
+            ),
+            ExecutionFailureStatus::TooManyEvents { count, max } => {
+                write!(
+                    f,
+                    "Transaction emitted {count} events, the maximum is {max}."
+                )
+            }
         }
     }
 }
@@ -1472,6 +2541,25 @@ impl ExecutionStatus {
         matches!(self, ExecutionStatus::Failure { .. })
     }
 
+    /// Returns `Some(())` if the execution succeeded, `None` otherwise.
+    /// Unlike `unwrap`, this never panics and is safe to use on untrusted effects.
+    pub fn ok(&self) -> Option<()> {
+        match self {
+            ExecutionStatus::Success => Some(()),
+            ExecutionStatus::Failure { .. } => None,
+        }
+    }
+
+    /// Returns the failure status if the execution failed, `None` otherwise.
+    /// Unlike `unwrap_err`, this never panics and is safe to use on untrusted effects.
+    pub fn err(&self) -> Option<&ExecutionFailureStatus> {
+        match self {
+            ExecutionStatus::Success => None,
+            ExecutionStatus::Failure { error } => Some(error),
+        }
+    }
+
+    /// Panics if the execution failed. Intended for tests only, use `ok` in server code.
     pub fn unwrap(self) {
         match self {
             ExecutionStatus::Success => {}
@@ -1481,6 +2569,7 @@ impl ExecutionStatus {
         }
     }
 
+    /// Panics if the execution succeeded. Intended for tests only, use `err` in server code.
     pub fn unwrap_err(self) -> ExecutionFailureStatus {
         match self {
             ExecutionStatus::Success { .. } => {
@@ -1521,6 +2610,17 @@ impl From<InvalidSharedByValue> for ExecutionFailureStatus {
     }
 }
 
+/// The maximum number of transaction digests recorded in `TransactionEffects::dependencies`.
+/// Dependencies are the distinct previous transactions of the input objects, so this also
+/// caps how many independently-written objects a single transaction can consume.
+pub const MAX_DEPENDENCIES: usize = 1024;
+
+/// The maximum number of events recorded in `TransactionEffects::events`, system events
+/// included. This keeps a single transaction from flooding event subscribers and stores.
+/// Execution that emits more fails with `ExecutionFailureStatus::TooManyEvents`, so the
+/// transaction is reverted and charged gas like any other failure.
+pub const MAX_EVENTS_PER_TX: usize = 1024;
+
 /// The response from processing a transaction or a certified transaction
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct TransactionEffects {
@@ -1552,7 +2652,156 @@ pub struct TransactionEffects {
     pub dependencies: Vec<TransactionDigest>,
 }
 
+/// First byte of effects encoded by `TransactionEffects::to_versioned_bytes`. Records in the
+/// layout that predates the `events` field start with the BCS variant index of their
+/// `ExecutionStatus`, which is 0 or 1, so they are never mistaken for tagged records.
+const TRANSACTION_EFFECTS_WITH_EVENTS_TAG: u8 = 2;
+
+/// Layout of `TransactionEffects` before the `events` field was added.
+#[derive(Serialize, Deserialize)]
+struct LegacyTransactionEffects {
+    status: ExecutionStatus,
+    gas_used: GasCostSummary,
+    shared_objects: Vec<ObjectRef>,
+    transaction_digest: TransactionDigest,
+    created: Vec<(ObjectRef, Owner)>,
+    mutated: Vec<(ObjectRef, Owner)>,
+    unwrapped: Vec<(ObjectRef, Owner)>,
+    deleted: Vec<ObjectRef>,
+    wrapped: Vec<ObjectRef>,
+    gas_object: (ObjectRef, Owner),
+    dependencies: Vec<TransactionDigest>,
+}
+
+impl From<LegacyTransactionEffects> for TransactionEffects {
+    fn from(legacy: LegacyTransactionEffects) -> Self {
+        Self {
+            status: legacy.status,
+            gas_used: legacy.gas_used,
+            shared_objects: legacy.shared_objects,
+            transaction_digest: legacy.transaction_digest,
+            created: legacy.created,
+            mutated: legacy.mutated,
+            unwrapped: legacy.unwrapped,
+            deleted: legacy.deleted,
+            wrapped: legacy.wrapped,
+            gas_object: legacy.gas_object,
+            events: vec![],
+            dependencies: legacy.dependencies,
+        }
+    }
+}
+
 impl TransactionEffects {
+    /// Encode these effects for long-term storage: a version tag followed by their BCS
+    /// encoding, so that `from_versioned_bytes` can tell them apart from older layouts.
+    pub fn to_versioned_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![TRANSACTION_EFFECTS_WITH_EVENTS_TAG];
+        bytes.extend(bcs::to_bytes(self).expect("TransactionEffects serialization cannot fail"));
+        bytes
+    }
+
+    /// Decode effects written by `to_versioned_bytes`. Untagged bytes are read in the layout
+    /// that predates the `events` field, so historical records load with no events.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+        match bytes.split_first() {
+            Some((&TRANSACTION_EFFECTS_WITH_EVENTS_TAG, effects)) => bcs::from_bytes(effects),
+            _ => bcs::from_bytes::<LegacyTransactionEffects>(bytes).map(Self::from),
+        }
+    }
+
+    /// The BCS encoding of these effects with every object and dependency list sorted, so
+    /// that effects that differ only in the order of those lists encode to the same bytes.
+    /// Use this as a key when comparing effects across nodes. Events are left in emission
+    /// order, which is meaningful.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut effects = self.clone();
+        effects.shared_objects.sort();
+        effects.created.sort();
+        effects.mutated.sort();
+        effects.unwrapped.sort();
+        effects.deleted.sort();
+        effects.wrapped.sort();
+        effects.dependencies.sort();
+        bcs::to_bytes(&effects).expect("BCS serialization of TransactionEffects cannot fail")
+    }
+
+    /// Check that no more than `MAX_DEPENDENCIES` dependencies are recorded. Authorities
+    /// refuse to sign transactions with more input objects than that, so the effects of
+    /// any certificate pass this check.
+    pub fn validate_dependency_bound(&self) -> SuiResult {
+        fp_ensure!(
+            self.dependencies.len() <= MAX_DEPENDENCIES,
+            SuiError::TooManyTransactionDependencies {
+                count: self.dependencies.len(),
+                max: MAX_DEPENDENCIES,
+            }
+        );
+        Ok(())
+    }
+
+    /// Group the events of this transaction by the package they originate from: the package
+    /// defining the event type for Move events, and the package of the emitting module for
+    /// system events. Events that don't come from a package, such as epoch changes and
+    /// checkpoints, are left out. Within a package, events keep their emission order.
+    pub fn events_by_package(&self) -> BTreeMap<ObjectID, Vec<&Event>> {
+        let mut events: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for event in &self.events {
+            let package = match event {
+                Event::MoveEvent { type_, .. } => Some(ObjectID::from(type_.address)),
+                _ => event.package_id(),
+            };
+            if let Some(package) = package {
+                events.entry(package).or_default().push(event);
+            }
+        }
+        events
+    }
+
+    /// Return the created objects whose type is `type_tag`. Effects only record references
+    /// and owners, so the caller supplies `resolver` to look up the type of an object, e.g.
+    /// from a local object store or a full node. Objects the resolver cannot find (returns
+    /// `None` for) are treated as not matching.
+    pub fn created_of_type<F>(&self, type_tag: &TypeTag, resolver: F) -> Vec<&(ObjectRef, Owner)>
+    where
+        F: Fn(&ObjectID) -> Option<TypeTag>,
+    {
+        self.created
+            .iter()
+            .filter(|((id, _, _), _)| resolver(id).as_ref() == Some(type_tag))
+            .collect()
+    }
+
+    /// Return the coins that `addr` received in this transaction, e.g. to detect deposits:
+    /// the created and mutated objects now owned by `addr`, excluding the gas object. Effects
+    /// don't record object types, so the caller supplies `resolver` to tell whether an object
+    /// is a coin, e.g. by looking it up in a local object store or a full node. Failed
+    /// transactions transfer nothing and always return an empty list.
+    pub fn received_coin_by<F>(&self, addr: SuiAddress, resolver: F) -> Vec<ObjectRef>
+    where
+        F: Fn(&ObjectID) -> bool,
+    {
+        if !self.status.is_ok() {
+            return vec![];
+        }
+        self.created
+            .iter()
+            .chain(self.mutated_excluding_gas())
+            .filter(|(_, owner)| *owner == Owner::AddressOwner(addr))
+            .map(|(object_ref, _)| *object_ref)
+            .filter(|(id, _, _)| resolver(id))
+            .collect()
+    }
+
+    /// The objects created by this transaction as shared objects.
+    pub fn created_shared_objects(&self) -> Vec<ObjectRef> {
+        self.created
+            .iter()
+            .filter(|(_, owner)| *owner == Owner::Shared)
+            .map(|(object_ref, _)| *object_ref)
+            .collect()
+    }
+
     /// Return an iterator that iterates through all mutated objects, including mutated,
     /// created and unwrapped objects. In other words, all objects that still exist
     /// in the object state after this transaction.
@@ -1574,6 +2823,30 @@ impl TransactionEffects {
         self.mutated.iter().filter(|o| *o != &self.gas_object)
     }
 
+    /// Return the references of all objects that are no longer independently addressable
+    /// after this transaction, i.e. deleted objects followed by wrapped objects, without
+    /// duplicates.
+    pub fn removed_object_refs(&self) -> Vec<ObjectRef> {
+        let mut seen = HashSet::new();
+        self.deleted
+            .iter()
+            .chain(&self.wrapped)
+            .filter(|obj_ref| seen.insert(**obj_ref))
+            .copied()
+            .collect()
+    }
+
+    /// Whether this transaction changed no object other than its gas object, e.g. a call to
+    /// a function that does nothing. Failed transactions are no-ops by this definition, as
+    /// their changes are reverted. Emitted events are not taken into account.
+    pub fn is_noop(&self) -> bool {
+        self.mutated_excluding_gas().next().is_none()
+            && self.created.is_empty()
+            && self.deleted.is_empty()
+            && self.wrapped.is_empty()
+            && self.unwrapped.is_empty()
+    }
+
     pub fn gas_cost_summary(&self) -> &GasCostSummary {
         &self.gas_used
     }
@@ -1631,6 +2904,38 @@ impl TransactionEffects {
     }
 }
 
+/// Compute the digest of BCS-encoded `TransactionEffects` without building an envelope.
+/// The bytes must be in the current layout; anything else is rejected as malformed.
+pub fn effects_digest_from_bytes(bytes: &[u8]) -> SuiResult<TransactionEffectsDigest> {
+    let effects: TransactionEffects =
+        bcs::from_bytes(bytes).map_err(|err| SuiError::TransactionEffectsDeserializationError {
+            error: err.to_string(),
+        })?;
+    Ok(effects.digest())
+}
+
+/// Sum the gas cost summaries of `effects` field by field, e.g. to total the gas of a
+/// checkpoint. Each field saturates at `u64::MAX` rather than overflowing.
+pub fn sum_gas_summaries<'a>(
+    effects: impl IntoIterator<Item = &'a TransactionEffects>,
+) -> GasCostSummary {
+    effects.into_iter().fold(
+        GasCostSummary {
+            computation_cost: 0,
+            storage_cost: 0,
+            storage_rebate: 0,
+        },
+        |total, effects| {
+            let gas = effects.gas_cost_summary();
+            GasCostSummary {
+                computation_cost: total.computation_cost.saturating_add(gas.computation_cost),
+                storage_cost: total.storage_cost.saturating_add(gas.storage_cost),
+                storage_rebate: total.storage_rebate.saturating_add(gas.storage_rebate),
+            }
+        },
+    )
+}
+
 impl Display for TransactionEffects {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut writer = String::new();
@@ -1694,6 +2999,12 @@ impl SignedTransactionEffects {
     pub fn verify(&self, committee: &Committee) -> SuiResult {
         self.auth_signature.verify(&self.effects, committee)
     }
+
+    /// Whether both envelopes carry the same effects, regardless of which authority signed
+    /// them. Authorities disagreeing on the effects of the same transaction indicates a fork.
+    pub fn effects_equal(&self, other: &SignedTransactionEffects) -> bool {
+        self.effects == other.effects
+    }
 }
 
 impl PartialEq for SignedTransactionEffects {
@@ -1702,6 +3013,11 @@ impl PartialEq for SignedTransactionEffects {
     }
 }
 
+/// Whether `effects` are the effects of `tx`, e.g. to catch mis-paired records in logs.
+pub fn correlate(tx: &SignedTransaction, effects: &SignedTransactionEffects) -> bool {
+    effects.effects.transaction_digest == *tx.digest()
+}
+
 pub type CertifiedTransactionEffects = TransactionEffectsEnvelope<AuthorityStrongQuorumSignInfo>;
 
 impl CertifiedTransactionEffects {
@@ -1736,6 +3052,9 @@ pub enum InputObjectKind {
     ImmOrOwnedMoveObject(ObjectRef),
     // A Move object that's shared and mutable.
     SharedMoveObject(ObjectID),
+    // A Move object that's shared and only read. It is sequenced and versioned like a
+    // mutable shared object, but execution must not mutate it.
+    ReadonlySharedMoveObject(ObjectID),
 }
 
 impl InputObjectKind {
@@ -1743,15 +3062,27 @@ impl InputObjectKind {
         match self {
             Self::MovePackage(id) => *id,
             Self::ImmOrOwnedMoveObject((id, _, _)) => *id,
-            Self::SharedMoveObject(id) => *id,
+            Self::SharedMoveObject(id) | Self::ReadonlySharedMoveObject(id) => *id,
         }
     }
 
+    /// The version of the input object, if the kind determines it. Shared objects are
+    /// only assigned a version when the transaction is sequenced, so `OBJECT_START_VERSION`
+    /// is returned for them as a placeholder; see `requires_version_resolution`.
     pub fn version(&self) -> SequenceNumber {
         match self {
             Self::MovePackage(..) => OBJECT_START_VERSION,
             Self::ImmOrOwnedMoveObject((_, version, _)) => *version,
-            Self::SharedMoveObject(..) => OBJECT_START_VERSION,
+            Self::SharedMoveObject(..) | Self::ReadonlySharedMoveObject(..) => OBJECT_START_VERSION,
+        }
+    }
+
+    /// Whether the actual version of the input must be resolved from the store, e.g. from
+    /// the shared object locks of the transaction, rather than taken from `version`.
+    pub fn requires_version_resolution(&self) -> bool {
+        match self {
+            Self::MovePackage(..) | Self::ImmOrOwnedMoveObject(..) => false,
+            Self::SharedMoveObject(..) | Self::ReadonlySharedMoveObject(..) => true,
         }
     }
 
@@ -1759,11 +3090,27 @@ impl InputObjectKind {
         match *self {
             Self::MovePackage(package_id) => SuiError::DependentPackageNotFound { package_id },
             Self::ImmOrOwnedMoveObject((object_id, _, _)) => SuiError::ObjectNotFound { object_id },
-            Self::SharedMoveObject(object_id) => SuiError::ObjectNotFound { object_id },
+            Self::SharedMoveObject(object_id) | Self::ReadonlySharedMoveObject(object_id) => {
+                SuiError::ObjectNotFound { object_id }
+            }
         }
     }
 }
 
+/// Return the input object kinds whose objects are absent from `present`.
+/// This is intended for pre-flight checks before constructing `InputObjects`.
+pub fn missing_input_objects(
+    kinds: &[InputObjectKind],
+    present: &BTreeMap<ObjectID, Object>,
+) -> Vec<InputObjectKind> {
+    kinds
+        .iter()
+        .filter(|kind| !present.contains_key(&kind.object_id()))
+        .inspect(|kind| debug!(error = ?kind.object_not_found_error(), "Missing input object"))
+        .copied()
+        .collect()
+}
+
 pub struct InputObjects {
     objects: Vec<(InputObjectKind, Object)>,
 }
@@ -1773,6 +3120,24 @@ impl InputObjects {
         Self { objects }
     }
 
+    /// Fetches each input object of `data` and pairs it with its kind. Like the authority
+    /// store, all missing objects are reported at once in `SuiError::ObjectErrors`.
+    pub fn load<F>(data: &TransactionData, fetch: F) -> SuiResult<Self>
+    where
+        F: Fn(ObjectID) -> Option<Object>,
+    {
+        let mut objects = Vec::new();
+        let mut errors = Vec::new();
+        for kind in data.input_objects()? {
+            match fetch(kind.object_id()) {
+                Some(object) => objects.push((kind, object)),
+                None => errors.push(kind.object_not_found_error()),
+            }
+        }
+        fp_ensure!(errors.is_empty(), SuiError::ObjectErrors { errors });
+        Ok(Self::new(objects))
+    }
+
     pub fn len(&self) -> usize {
         self.objects.len()
     }
@@ -1794,7 +3159,8 @@ impl InputObjects {
                         Some(*object_ref)
                     }
                 }
-                InputObjectKind::SharedMoveObject(_) => None,
+                InputObjectKind::SharedMoveObject(_)
+                | InputObjectKind::ReadonlySharedMoveObject(_) => None,
             })
             .collect();
 
@@ -1809,7 +3175,22 @@ impl InputObjects {
     pub fn filter_shared_objects(&self) -> Vec<ObjectRef> {
         self.objects
             .iter()
-            .filter(|(kind, _)| matches!(kind, InputObjectKind::SharedMoveObject(_)))
+            .filter(|(kind, _)| {
+                matches!(
+                    kind,
+                    InputObjectKind::SharedMoveObject(_)
+                        | InputObjectKind::ReadonlySharedMoveObject(_)
+                )
+            })
+            .map(|(_, obj)| obj.compute_object_reference())
+            .collect()
+    }
+
+    /// The references of the packages loaded as inputs, as found in the store.
+    pub fn resolved_package_refs(&self) -> Vec<ObjectRef> {
+        self.objects
+            .iter()
+            .filter(|(kind, _)| matches!(kind, InputObjectKind::MovePackage(_)))
             .map(|(_, obj)| obj.compute_object_reference())
             .collect()
     }
@@ -1834,6 +3215,28 @@ impl InputObjects {
                     }
                 }
                 InputObjectKind::SharedMoveObject(_) => Some(object.compute_object_reference()),
+                // Read-only shared objects are not written, so their version is not bumped
+                // and they do not contend with other readers.
+                InputObjectKind::ReadonlySharedMoveObject(_) => None,
+            })
+            .collect()
+    }
+
+    /// The inputs that the transaction reads but never mutates, i.e. packages, immutable
+    /// objects and read-only shared objects. These are exactly the inputs left out of
+    /// `mutable_inputs`.
+    pub fn readonly_inputs(&self) -> Vec<ObjectRef> {
+        self.objects
+            .iter()
+            .filter_map(|(kind, object)| match kind {
+                InputObjectKind::MovePackage(_) => Some(object.compute_object_reference()),
+                InputObjectKind::ImmOrOwnedMoveObject(object_ref) => {
+                    object.is_immutable().then_some(*object_ref)
+                }
+                InputObjectKind::ReadonlySharedMoveObject(_) => {
+                    Some(object.compute_object_reference())
+                }
+                InputObjectKind::SharedMoveObject(_) => None,
             })
             .collect()
     }
@@ -1857,15 +3260,22 @@ impl From<Vec<Object>> for InputObjects {
     }
 }
 
-pub struct SignatureAggregator<'a> {
+/// Aggregates authority signatures on a transaction until they reach the quorum threshold
+/// (2f+1) when `STRONG_THRESHOLD` is true, or the validity threshold (f+1) otherwise.
+pub struct QuorumSignatureAggregator<'a, const STRONG_THRESHOLD: bool> {
     committee: &'a Committee,
     weight: StakeUnit,
     used_authorities: HashSet<AuthorityName>,
-    partial: CertifiedTransaction,
+    partial: TransactionEnvelope<AuthorityQuorumSignInfo<STRONG_THRESHOLD>>,
     signature_stash: Vec<(AuthorityName, AuthoritySignature)>,
 }
 
-impl<'a> SignatureAggregator<'a> {
+/// Aggregates signatures into a `CertifiedTransaction`.
+pub type SignatureAggregator<'a> = QuorumSignatureAggregator<'a, true>;
+/// Aggregates signatures into a `WeakCertifiedTransaction`.
+pub type WeakSignatureAggregator<'a> = QuorumSignatureAggregator<'a, false>;
+
+impl<'a, const STRONG_THRESHOLD: bool> QuorumSignatureAggregator<'a, STRONG_THRESHOLD> {
     /// Start aggregating signatures for the given value into a certificate.
     pub fn try_new(transaction: Transaction, committee: &'a Committee) -> Result<Self, SuiError> {
         transaction.verify()?;
@@ -1878,11 +3288,24 @@ impl<'a> SignatureAggregator<'a> {
             committee,
             weight: 0,
             used_authorities: HashSet::new(),
-            partial: CertifiedTransaction::new(committee.epoch, transaction),
+            partial: TransactionEnvelope {
+                transaction_digest: transaction.transaction_digest,
+                is_verified: false,
+                signed_data: transaction.signed_data,
+                auth_sign_info: AuthorityQuorumSignInfo::new(committee.epoch),
+            },
             signature_stash: Vec::new(),
         }
     }
 
+    fn threshold(&self) -> StakeUnit {
+        if STRONG_THRESHOLD {
+            self.committee.quorum_threshold()
+        } else {
+            self.committee.validity_threshold()
+        }
+    }
+
     /// Try to append a signature to a (partial) certificate. Returns Some(certificate) if a quorum was reached.
     /// The resulting final certificate is guaranteed to be valid in the sense of `check` below.
     /// Returns an error if the signed value cannot be aggregated.
@@ -1890,7 +3313,8 @@ impl<'a> SignatureAggregator<'a> {
         &mut self,
         authority: AuthorityName,
         signature: AuthoritySignature,
-    ) -> Result<Option<CertifiedTransaction>, SuiError> {
+    ) -> Result<Option<TransactionEnvelope<AuthorityQuorumSignInfo<STRONG_THRESHOLD>>>, SuiError>
+    {
         signature.verify(&self.partial.signed_data, authority)?;
 
         // Check that each authority only appears once.
@@ -1907,8 +3331,8 @@ impl<'a> SignatureAggregator<'a> {
 
         self.signature_stash.push((authority, signature));
 
-        if self.weight >= self.committee.quorum_threshold() {
-            self.partial.auth_sign_info = AuthorityStrongQuorumSignInfo::new_with_signatures(
+        if self.weight >= self.threshold() {
+            self.partial.auth_sign_info = AuthorityQuorumSignInfo::new_with_signatures(
                 self.signature_stash.clone(),
                 self.committee,
             )?;
@@ -1917,6 +3341,39 @@ impl<'a> SignatureAggregator<'a> {
             Ok(None)
         }
     }
+
+    /// Suggest which authorities to contact next so that, if they all sign, the aggregated
+    /// weight crosses the threshold. Authorities that already contributed a signature
+    /// are skipped. Members of `preferred` are suggested first (in the given order), followed
+    /// by the remaining authorities sorted by stake descending. Returns an empty list if a
+    /// quorum was already reached.
+    pub fn suggest_next_authorities(&self, preferred: &[AuthorityName]) -> Vec<AuthorityName> {
+        let threshold = self.threshold();
+        let mut candidates: Vec<_> = self
+            .committee
+            .members()
+            .filter(|(name, _)| !self.used_authorities.contains(name))
+            .collect();
+        candidates.sort_by(|(a, a_stake), (b, b_stake)| {
+            let a_rank = preferred.iter().position(|p| p == a).unwrap_or(usize::MAX);
+            let b_rank = preferred.iter().position(|p| p == b).unwrap_or(usize::MAX);
+            a_rank
+                .cmp(&b_rank)
+                .then_with(|| b_stake.cmp(a_stake))
+                .then_with(|| a.cmp(b))
+        });
+
+        let mut weight = self.weight;
+        let mut suggested = Vec::new();
+        for (name, stake) in candidates {
+            if weight >= threshold {
+                break;
+            }
+            weight += stake;
+            suggested.push(*name);
+        }
+        suggested
+    }
 }
 
 impl CertifiedTransaction {
@@ -1948,6 +3405,157 @@ impl CertifiedTransaction {
         Transaction::new(self.signed_data.data, self.signed_data.tx_signature)
     }
 
+    /// Serialize only the fields that make up the certificate (the sender-signed data and
+    /// the quorum signature), without the type name wrapping of the `Serialize` impl. Meant
+    /// for caching and relaying; restore with `from_compact_bytes`.
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&(&self.signed_data, &self.auth_sign_info))
+            .expect("BCS serialization of a certificate should not fail")
+    }
+
+    /// Restore a certificate serialized with `to_compact_bytes`. The digest is recomputed
+    /// lazily, and the certificate is marked as not verified, so callers must `verify` it
+    /// before trusting it.
+    pub fn from_compact_bytes(bytes: &[u8]) -> SuiResult<Self> {
+        let (signed_data, auth_sign_info) =
+            bcs::from_bytes(bytes).map_err(|err| SuiError::TransactionDeserializationError {
+                error: err.to_string(),
+            })?;
+        Ok(CertifiedTransaction {
+            transaction_digest: OnceCell::new(),
+            is_verified: false,
+            signed_data,
+            auth_sign_info,
+        })
+    }
+
+    /// Like `to_compact_bytes`, but also records the `is_verified` flag, so a certificate
+    /// that was verified before a restart does not have to be verified again.
+    ///
+    /// For local trusted storage ONLY: these bytes must never be sent over the network or
+    /// read from anywhere a peer could have written, since whoever produces them decides
+    /// whether the certificate skips verification. Restore with `from_trusted_cache_bytes`.
+    pub fn to_trusted_cache_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&(&self.signed_data, &self.auth_sign_info, self.is_verified))
+            .expect("BCS serialization of a certificate should not fail")
+    }
+
+    /// Restore a certificate serialized with `to_trusted_cache_bytes`, including its
+    /// `is_verified` flag. The bytes are trusted as is, so they must come from local storage
+    /// written by this node, never from the network.
+    pub fn from_trusted_cache_bytes(bytes: &[u8]) -> SuiResult<Self> {
+        let (signed_data, auth_sign_info, is_verified) =
+            bcs::from_bytes(bytes).map_err(|err| SuiError::TransactionDeserializationError {
+                error: err.to_string(),
+            })?;
+        Ok(CertifiedTransaction {
+            transaction_digest: OnceCell::new(),
+            is_verified,
+            signed_data,
+            auth_sign_info,
+        })
+    }
+
+    pub fn epoch(&self) -> EpochId {
+        self.auth_sign_info.epoch
+    }
+
+    /// Whether `effects` are the effects of this certificate's transaction. A mismatch when
+    /// pairing the two, e.g. into a `TxCertAndSignedEffects`, indicates a bug or an attack.
+    pub fn matches_effects<S>(&self, effects: &TransactionEffectsEnvelope<S>) -> bool {
+        effects.effects.transaction_digest == *self.digest()
+    }
+
+    /// Pair every committee member, in `Committee::ordered_names` order, with whether it
+    /// signed this certificate.
+    fn committee_signatures(&self, committee: &Committee) -> Vec<(AuthorityName, bool)> {
+        committee
+            .ordered_names()
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let signed = self.auth_sign_info.signers_map.contains(index as u32);
+                (name, signed)
+            })
+            .collect()
+    }
+
+    /// Return the authorities that signed this certificate, in committee order.
+    /// Fails if the signers bitmap refers to authorities outside of the committee.
+    pub fn signing_authorities(&self, committee: &Committee) -> SuiResult<Vec<AuthorityName>> {
+        fp_ensure!(
+            self.auth_sign_info
+                .signers_map
+                .max()
+                .map_or(true, |index| (index as usize) < committee.num_members()),
+            SuiError::InvalidAuthenticator
+        );
+        Ok(self
+            .committee_signatures(committee)
+            .into_iter()
+            .filter_map(|(name, signed)| signed.then_some(name))
+            .collect())
+    }
+
+    /// Return the committee members that did not sign this certificate, in committee order.
+    /// Useful for spotting validators that are consistently lagging or unreachable.
+    pub fn non_signing_authorities(&self, committee: &Committee) -> Vec<AuthorityName> {
+        self.committee_signatures(committee)
+            .into_iter()
+            .filter_map(|(name, signed)| (!signed).then_some(name))
+            .collect()
+    }
+
+    /// Compare the signers of this certificate with those of `other`, e.g. two certificates
+    /// for the same transaction collected at different times. Returns the authorities that
+    /// signed only this certificate and those that signed only `other`, in committee order.
+    pub fn signer_difference(
+        &self,
+        other: &Self,
+        committee: &Committee,
+    ) -> (Vec<AuthorityName>, Vec<AuthorityName>) {
+        let mut only_self = Vec::new();
+        let mut only_other = Vec::new();
+        for ((name, in_self), (_, in_other)) in self
+            .committee_signatures(committee)
+            .into_iter()
+            .zip(other.committee_signatures(committee))
+        {
+            match (in_self, in_other) {
+                (true, false) => only_self.push(name),
+                (false, true) => only_other.push(name),
+                _ => (),
+            }
+        }
+        (only_self, only_other)
+    }
+
+    /// A rough, unitless estimate of the work needed to `verify` this certificate, meant for
+    /// ordering certificates in a verification queue rather than as an absolute measure.
+    /// Verification hashes the transaction for the sender signature and again for the
+    /// authority signatures, aggregates one public key per signer, and checks two signatures.
+    pub fn verification_cost_estimate(&self) -> usize {
+        // Cost of a single signature check, relative to hashing one byte.
+        const SIGNATURE_CHECK_COST: usize = 10_000;
+        // Cost of adding one signer's public key to the aggregate.
+        const PER_SIGNER_COST: usize = 500;
+
+        let signers = self.auth_sign_info.signers_map.len() as usize;
+        let message_size = self.signed_data.data.to_bytes().len();
+        2 * SIGNATURE_CHECK_COST + signers * PER_SIGNER_COST + 2 * message_size
+    }
+
+    /// Return the total stake of the authorities that signed this certificate.
+    pub fn signed_weight(&self, committee: &Committee) -> SuiResult<StakeUnit> {
+        Ok(self
+            .signing_authorities(committee)?
+            .iter()
+            .map(|name| committee.weight(name))
+            .sum())
+    }
+}
+
+impl<const STRONG_THRESHOLD: bool> TransactionEnvelope<AuthorityQuorumSignInfo<STRONG_THRESHOLD>> {
     /// Verify the certificate.
     pub fn verify(&self, committee: &Committee) -> Result<(), SuiError> {
         // We use this flag to see if someone has checked this before
@@ -1961,6 +3569,17 @@ impl CertifiedTransaction {
         // Add the obligation of the sender signature verification.
         self.verify_sender_signature()?;
 
+        self.verify_authority_signatures_only(committee)
+    }
+
+    /// Verify only the quorum of authority signatures, skipping the sender signature.
+    ///
+    /// This is meant for internal paths where the sender signature of the same transaction
+    /// has already been checked, e.g. re-executing a certificate whose transaction was
+    /// verified at ingress. A valid quorum does NOT imply a valid sender signature: authorities
+    /// are expected to check it before signing, but a certificate received from an untrusted
+    /// source must go through `verify` instead.
+    pub fn verify_authority_signatures_only(&self, committee: &Committee) -> SuiResult {
         let mut obligation = VerificationObligation::default();
         // Add the obligation of the authority signature verifications.
         let idx = obligation.add_message(&self.signed_data);
@@ -1969,10 +3588,6 @@ impl CertifiedTransaction {
 
         obligation.verify_all().map(|_| ())
     }
-
-    pub fn epoch(&self) -> EpochId {
-        self.auth_sign_info.epoch
-    }
 }
 
 impl Display for CertifiedTransaction {
@@ -2046,6 +3661,27 @@ impl ConsensusTransaction {
         }
     }
 
+    /// Returns the wrapped certificate, or None if this is a checkpoint fragment.
+    /// Read the tracking id with `get_tracking_id` first if it is still needed.
+    pub fn into_certificate(self) -> Option<CertifiedTransaction> {
+        match self.kind {
+            ConsensusTransactionKind::UserTransaction(certificate) => Some(*certificate),
+            ConsensusTransactionKind::Checkpoint(_) => None,
+        }
+    }
+
+    /// The proposer and the other authority of a checkpoint fragment, or None if this is a
+    /// user transaction.
+    pub fn checkpoint_participants(&self) -> Option<(AuthorityName, AuthorityName)> {
+        match &self.kind {
+            ConsensusTransactionKind::Checkpoint(fragment) => Some((
+                fragment.proposer.auth_signature.authority,
+                fragment.other.auth_signature.authority,
+            )),
+            ConsensusTransactionKind::UserTransaction(_) => None,
+        }
+    }
+
     pub fn get_tracking_id(&self) -> u64 {
         (&self.tracking_id[..])
             .read_u64::<BigEndian>()
@@ -2058,6 +3694,22 @@ impl ConsensusTransaction {
             ConsensusTransactionKind::Checkpoint(fragment) => fragment.verify(committee),
         }
     }
+
+    /// Like `verify`, but also rejects a user certificate that was not formed in
+    /// `expected_epoch`, so that a certificate from another epoch cannot slip through when
+    /// the wrong committee is passed.
+    pub fn verify_in_epoch(&self, committee: &Committee, expected_epoch: EpochId) -> SuiResult<()> {
+        if let ConsensusTransactionKind::UserTransaction(certificate) = &self.kind {
+            fp_ensure!(
+                certificate.epoch() == expected_epoch,
+                SuiError::WrongEpoch {
+                    expected_epoch,
+                    actual_epoch: certificate.epoch(),
+                }
+            );
+        }
+        self.verify(committee)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, schemars::JsonSchema)]
@@ -2066,6 +3718,9 @@ pub enum ExecuteTransactionRequestType {
     WaitForTxCert,
     WaitForEffectsCert,
     WaitForLocalExecution,
+    // Like WaitForEffectsCert, but if validators don't agree on effects with a quorum, the
+    // effects returned with the most stake behind them are returned uncertified.
+    BestEffortWithPartialEffects,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -2092,6 +3747,9 @@ pub enum ExecuteTransactionResponse {
             IsTransactionExecutedLocally,
         )>,
     ),
+    // Effects returned by the most stake when validators don't agree on a quorum. They are
+    // not certified.
+    PartialEffects(Box<TransactionEffects>),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, schemars::JsonSchema)]
@@ -2099,6 +3757,7 @@ pub enum QuorumDriverRequestType {
     ImmediateReturn,
     WaitForTxCert,
     WaitForEffectsCert,
+    WaitForEffectsCertOrPartialEffects,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -2113,6 +3772,17 @@ pub enum QuorumDriverResponse {
     TxCert(Box<CertifiedTransaction>),
     // TODO: Change to CertifiedTransactionEffects eventually.
     EffectsCert(Box<(CertifiedTransaction, CertifiedTransactionEffects)>),
+    PartialEffects(Box<(CertifiedTransaction, PartialTransactionEffects)>),
+}
+
+/// Effects that validators returned for a certificate without any of them reaching a quorum:
+/// the effects with the most stake behind them, and the errors from the other validators.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PartialTransactionEffects {
+    pub effects: TransactionEffects,
+    pub stake: StakeUnit,
+    pub signatures: Vec<(AuthorityName, AuthoritySignature)>,
+    pub errors: Vec<SuiError>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -2124,6 +3794,10 @@ pub struct CommitteeInfoRequest {
 pub struct CommitteeInfoResponse {
     pub epoch: EpochId,
     pub committee_info: Option<Vec<(AuthorityName, StakeUnit)>>,
+    /// The reference gas price of the epoch, if it is the serving authority's current epoch.
+    /// Covered by `digest`.
+    #[serde(default)]
+    pub reference_gas_price: Option<u64>,
     // TODO: We could also return the certified checkpoint that contains this committee.
     // This would allows a client to verify the authenticity of the committee.
 }
@@ -2136,6 +3810,34 @@ impl CommitteeInfoResponse {
     }
 }
 
+/// `CommitteeInfoResponse` as encoded before it carried the reference gas price. The binary
+/// encodings are not self-describing, so authorities keep serving this layout on the original
+/// `committee_info` route for existing clients, and serve the full response on `committee_info_v2`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LegacyCommitteeInfoResponse {
+    pub epoch: EpochId,
+    pub committee_info: Option<Vec<(AuthorityName, StakeUnit)>>,
+}
+
+impl From<CommitteeInfoResponse> for LegacyCommitteeInfoResponse {
+    fn from(response: CommitteeInfoResponse) -> Self {
+        Self {
+            epoch: response.epoch,
+            committee_info: response.committee_info,
+        }
+    }
+}
+
+impl From<LegacyCommitteeInfoResponse> for CommitteeInfoResponse {
+    fn from(response: LegacyCommitteeInfoResponse) -> Self {
+        Self {
+            epoch: response.epoch,
+            committee_info: response.committee_info,
+            reference_gas_price: None,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommitteeInfo {
     pub epoch: EpochId,
@@ -5,7 +5,7 @@ use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::slice::Iter;
 
-use crate::base_types::ExecutionDigests;
+use crate::base_types::{ExecutionDigests, TransactionDigest};
 use crate::committee::{EpochId, StakeUnit};
 use crate::crypto::{AuthoritySignInfo, AuthoritySignInfoTrait, AuthorityWeakQuorumSignInfo};
 use crate::error::SuiResult;
@@ -17,7 +17,9 @@ use crate::{
     crypto::{sha3_hash, AuthoritySignature, SuiAuthoritySignature, VerificationObligation},
     error::SuiError,
 };
+use digest::Digest;
 use serde::{Deserialize, Serialize};
+use sha3::Sha3_256;
 
 /*
 
@@ -172,6 +174,46 @@ impl AuthenticatedCheckpoint {
 pub type CheckpointDigest = [u8; 32];
 pub type CheckpointContentsDigest = [u8; 32];
 
+const COMMITMENT_LEAF_PREFIX: u8 = 0;
+const COMMITMENT_NODE_PREFIX: u8 = 1;
+
+/// Compute a binary Merkle tree commitment over an ordered list of transaction digests, so
+/// that the inclusion of a digest can later be proven with a logarithmic-size path. Leaves
+/// and inner nodes are hashed with distinct prefixes, and a node without a sibling is carried
+/// up to the next level unchanged. The commitment of an empty list is the hash of no input.
+pub fn transaction_digests_commitment(digests: &[TransactionDigest]) -> [u8; 32] {
+    if digests.is_empty() {
+        return Sha3_256::digest(b"").into();
+    }
+
+    let mut level: Vec<[u8; 32]> = digests
+        .iter()
+        .map(|digest| {
+            let mut hasher = Sha3_256::default();
+            hasher.update([COMMITMENT_LEAF_PREFIX]);
+            hasher.update(digest);
+            hasher.finalize().into()
+        })
+        .collect();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => {
+                    let mut hasher = Sha3_256::default();
+                    hasher.update([COMMITMENT_NODE_PREFIX]);
+                    hasher.update(left);
+                    hasher.update(right);
+                    hasher.finalize().into()
+                }
+                [single] => *single,
+                _ => unreachable!("chunks(2) yields one or two elements"),
+            })
+            .collect();
+    }
+    level[0]
+}
+
 // The constituent parts of checkpoints, signed and certified
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -803,4 +845,40 @@ mod tests {
         let fragment2 = proposal1.fragment_with(&proposal3);
         assert!(fragment2.verify(&committee).is_err());
     }
+
+    #[test]
+    fn test_transaction_digests_commitment() {
+        let digests: Vec<_> = (0..5).map(|_| TransactionDigest::random()).collect();
+        let commitment = transaction_digests_commitment(&digests);
+
+        // Deterministic.
+        assert_eq!(commitment, transaction_digests_commitment(&digests));
+
+        // Order-sensitive.
+        let mut swapped = digests.clone();
+        swapped.swap(0, 1);
+        assert_ne!(commitment, transaction_digests_commitment(&swapped));
+
+        // Changes with any single digest, including the one carried up without a sibling.
+        for i in 0..digests.len() {
+            let mut changed = digests.clone();
+            changed[i] = TransactionDigest::random();
+            assert_ne!(commitment, transaction_digests_commitment(&changed));
+        }
+
+        // Not a flat hash of the digests, and a prefix does not commit to the same value.
+        let flat: [u8; 32] = digests
+            .iter()
+            .fold(Sha3_256::default(), |hasher, digest| {
+                hasher.chain_update(digest)
+            })
+            .finalize()
+            .into();
+        assert_ne!(commitment, flat);
+        assert_ne!(commitment, transaction_digests_commitment(&digests[..4]));
+        assert_ne!(
+            transaction_digests_commitment(&[]),
+            transaction_digests_commitment(&digests[..1])
+        );
+    }
 }
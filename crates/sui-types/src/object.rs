@@ -47,7 +47,7 @@ pub struct MoveObject {
 pub const ID_END_INDEX: usize = ObjectID::LENGTH;
 
 /// Different schemes for converting a Move value into a structured representation
-#[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize, Hash)]
+#[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize, Hash, JsonSchema)]
 pub struct ObjectFormatOptions {
     /// If true, include the type of each object as well as its fields; e.g.:
     /// `{ "fields": { "f": 20, "g": { "fields" { "h": true }, "type": "0x0::MyModule::MyNestedType" }, "type": "0x0::MyModule::MyType" }`
@@ -596,10 +596,12 @@ impl Object {
         }
         let has_public_transfer = match &self.data {
             Data::Move(m) => m.has_public_transfer(),
-            Data::Package(_) => false,
+            Data::Package(_) => return Err(ExecutionErrorKind::InvalidTransferObject.into()),
         };
         if !has_public_transfer {
-            return Err(ExecutionErrorKind::InvalidTransferObject.into());
+            return Err(
+                ExecutionErrorKind::transfer_object_without_public_transfer(self.id()).into(),
+            );
         }
         Ok(())
     }
@@ -626,6 +628,12 @@ impl ObjectRead {
     }
 }
 
+impl ObjectFormatOptions {
+    pub fn new(include_types: bool) -> Self {
+        ObjectFormatOptions { include_types }
+    }
+}
+
 impl Default for ObjectFormatOptions {
     fn default() -> Self {
         ObjectFormatOptions {
@@ -21,6 +21,8 @@ use crate::{
 const SUI_SYSTEM_STATE_STRUCT_NAME: &IdentStr = ident_str!("SuiSystemState");
 pub const SUI_SYSTEM_MODULE_NAME: &IdentStr = ident_str!("sui_system");
 pub const ADVANCE_EPOCH_FUNCTION_NAME: &IdentStr = ident_str!("advance_epoch");
+pub const CONSENSUS_COMMIT_PROLOGUE_FUNCTION_NAME: &IdentStr =
+    ident_str!("consensus_commit_prologue");
 
 /// Rust version of the Move sui::sui_system::SystemParameters type
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
@@ -128,10 +130,54 @@ pub struct SuiSystemState {
     pub storage_fund: Balance,
     pub parameters: SystemParameters,
     pub reference_gas_price: u64,
+    pub consensus_round: u64,
+    pub consensus_commit_timestamp_ms: u64,
     // TODO: Use getters instead of all pub.
 }
 
+/// Layout of `SuiSystemState` in genesis blobs created before the consensus commit clock.
+/// Packages cannot be upgraded, so networks started from such a genesis keep this layout
+/// and the framework that goes with it, which has no `consensus_commit_prologue`.
+#[derive(Deserialize)]
+struct SuiSystemStateWithoutClock {
+    info: UID,
+    epoch: u64,
+    validators: ValidatorSet,
+    treasury_cap: Supply,
+    storage_fund: Balance,
+    parameters: SystemParameters,
+    reference_gas_price: u64,
+}
+
+impl From<SuiSystemStateWithoutClock> for SuiSystemState {
+    fn from(state: SuiSystemStateWithoutClock) -> Self {
+        Self {
+            info: state.info,
+            epoch: state.epoch,
+            validators: state.validators,
+            treasury_cap: state.treasury_cap,
+            storage_fund: state.storage_fund,
+            parameters: state.parameters,
+            reference_gas_price: state.reference_gas_price,
+            consensus_round: 0,
+            consensus_commit_timestamp_ms: 0,
+        }
+    }
+}
+
 impl SuiSystemState {
+    /// Decode the contents of the system state object. Objects in the layout that predates
+    /// the consensus commit clock decode with the clock at 0, its value at genesis; the
+    /// clock of such a network never advances, since its framework cannot run the commit
+    /// prologue, so transaction version 2 must not be enabled on it.
+    pub fn from_bcs_bytes(bytes: &[u8]) -> Result<Self, bcs::Error> {
+        bcs::from_bytes(bytes).or_else(|err| {
+            bcs::from_bytes::<SuiSystemStateWithoutClock>(bytes)
+                .map(Self::from)
+                .map_err(|_| err)
+        })
+    }
+
     pub fn type_() -> StructTag {
         StructTag {
             address: SUI_FRAMEWORK_ADDRESS,
@@ -108,6 +108,10 @@ impl<S> TemporaryStore<S> {
         &self.input_objects
     }
 
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
     pub fn deleted(&self) -> &BTreeMap<ObjectID, (SequenceNumber, DeleteKind)> {
         &self.deleted
     }
@@ -0,0 +1,76 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::base_types::{ObjectDigest, ObjectID, SequenceNumber};
+
+fn coins(balances: &[u64]) -> Vec<(ObjectRef, u64)> {
+    balances
+        .iter()
+        .map(|balance| {
+            let coin = (
+                ObjectID::random(),
+                SequenceNumber::new(),
+                ObjectDigest::new([0; 32]),
+            );
+            (coin, *balance)
+        })
+        .collect()
+}
+
+#[test]
+fn test_select_coins_exact_match() {
+    let coins = coins(&[60, 10]);
+    let selected = select_coins(&coins, 50, 10).unwrap();
+    assert_eq!(selected, vec![coins[0].0]);
+}
+
+#[test]
+fn test_select_coins_multiple_coins() {
+    let coins = coins(&[10, 40, 30]);
+    let selected = select_coins(&coins, 60, 10).unwrap();
+    // Largest coins first, and no more than needed.
+    assert_eq!(selected, vec![coins[1].0, coins[2].0]);
+
+    let selected = select_coins(&coins, 70, 10).unwrap();
+    assert_eq!(selected, vec![coins[1].0, coins[2].0, coins[0].0]);
+}
+
+#[test]
+fn test_select_coins_insufficient_funds() {
+    let coins = coins(&[10, 40, 30]);
+    assert_eq!(
+        select_coins(&coins, 75, 10),
+        Err(SuiError::InsufficientBalance {
+            balance: 80,
+            required: 85
+        })
+    );
+    assert!(matches!(
+        select_coins(&coins, u64::MAX, u64::MAX),
+        Err(SuiError::InsufficientBalance { .. })
+    ));
+    assert!(matches!(
+        select_coins(&[], 1, 0),
+        Err(SuiError::InsufficientBalance { .. })
+    ));
+}
+
+#[test]
+fn test_account_balance() {
+    let objects = coins(&[10, 40, 30]);
+    let object_ids: Vec<_> = objects.iter().map(|(object_ref, _)| *object_ref).collect();
+    // Only the first and last objects are coins.
+    let resolver = |object_ref: &ObjectRef| {
+        objects
+            .iter()
+            .enumerate()
+            .find(|(i, (coin, _))| coin == object_ref && *i != 1)
+            .map(|(_, (_, balance))| *balance)
+    };
+
+    assert_eq!(account_balance(&object_ids, resolver), 40);
+    assert_eq!(account_balance(&object_ids, |_| None), 0);
+    assert_eq!(account_balance(&[], resolver), 0);
+    assert_eq!(account_balance(&object_ids, |_| Some(u64::MAX)), u64::MAX);
+}
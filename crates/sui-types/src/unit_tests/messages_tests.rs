@@ -3,19 +3,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::hash_map::DefaultHasher;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use fastcrypto::traits::AggregateAuthenticator;
 use fastcrypto::traits::KeyPair;
+use move_binary_format::file_format;
+use move_core_types::identifier::Identifier;
+use rand::{rngs::StdRng, SeedableRng};
 use roaring::RoaringBitmap;
 
 use crate::crypto::bcs_signable_test::{get_obligation_input, Foo};
 use crate::crypto::Secp256k1SuiSignature;
 use crate::crypto::SuiKeyPair;
 use crate::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair, AuthorityPublicKeyBytes};
+use crate::gas_coin::GasCoin;
 use crate::messages_checkpoint::CheckpointContents;
 use crate::messages_checkpoint::CheckpointSummary;
+use crate::messages_checkpoint::{CheckpointProposal, CheckpointProposalContents};
 use crate::object::Owner;
+use crate::utils::{make_committee_key, TransactionEffectsBuilder};
 
 use super::*;
 fn random_object_ref() -> ObjectRef {
@@ -26,6 +32,70 @@ fn random_object_ref() -> ObjectRef {
     )
 }
 
+fn make_certificate_signed_by(
+    keys: &[AuthorityKeyPair],
+    committee: &Committee,
+) -> CertifiedTransaction {
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            sender,
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            10000,
+        ),
+        &sender_sec,
+    );
+    let signatures = keys
+        .iter()
+        .map(|key| {
+            (
+                AuthorityPublicKeyBytes::from(key.public()),
+                AuthoritySignature::new(&transaction.signed_data, key),
+            )
+        })
+        .collect();
+    CertifiedTransaction::new_with_signatures(transaction, signatures, committee).unwrap()
+}
+
+#[test]
+fn test_verify_authority_signatures_only() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let (_, other_sec): (_, AccountKeyPair) = get_key_pair();
+    // The transaction is signed by a key that does not belong to the sender.
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            sender,
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            10000,
+        ),
+        &other_sec,
+    );
+    let signatures = keys[..3]
+        .iter()
+        .map(|key| {
+            (
+                AuthorityPublicKeyBytes::from(key.public()),
+                AuthoritySignature::new(&transaction.signed_data, key),
+            )
+        })
+        .collect();
+    let cert =
+        CertifiedTransaction::new_with_signatures(transaction, signatures, &committee).unwrap();
+
+    // Only the full verification catches the bad sender signature.
+    assert!(cert.verify(&committee).is_err());
+    assert!(cert.verify_authority_signatures_only(&committee).is_ok());
+
+    // A certificate without a quorum is still rejected.
+    let cert = make_certificate_signed_by(&keys[..1], &committee);
+    assert!(cert.verify_authority_signatures_only(&committee).is_err());
+}
+
 #[test]
 fn test_signed_values() {
     let mut authorities: BTreeMap<AuthorityPublicKeyBytes, u64> = BTreeMap::new();
@@ -186,6 +256,115 @@ fn test_certificates() {
     assert!(SignatureAggregator::try_new(bad_transaction, &committee).is_err());
 }
 
+#[test]
+fn test_weak_signature_aggregator() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            sender,
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            10000,
+        ),
+        &sender_sec,
+    );
+    let signatures: Vec<_> = keys
+        .iter()
+        .map(|key| {
+            (
+                AuthorityPublicKeyBytes::from(key.public()),
+                AuthoritySignature::new(&transaction.signed_data, key),
+            )
+        })
+        .collect();
+
+    // With 4 authorities of equal stake, f+1 is 2 signatures.
+    assert_eq!(committee.validity_threshold(), 2);
+    let mut builder = WeakSignatureAggregator::try_new(transaction.clone(), &committee).unwrap();
+    let (name, signature) = signatures[0].clone();
+    assert!(builder.append(name, signature).unwrap().is_none());
+    assert_eq!(builder.suggest_next_authorities(&[]).len(), 1);
+    let (name, signature) = signatures[1].clone();
+    let weak_cert = builder.append(name, signature).unwrap().unwrap();
+    assert!(weak_cert.verify(&committee).is_ok());
+    assert!(builder.suggest_next_authorities(&[]).is_empty());
+
+    // The same signatures do not make a (strong) certificate.
+    let cert = CertifiedTransaction::new_with_signatures(
+        transaction,
+        signatures[..2].to_vec(),
+        &committee,
+    )
+    .unwrap();
+    assert_eq!(
+        cert.verify(&committee),
+        Err(SuiError::CertificateRequiresQuorum)
+    );
+}
+
+#[test]
+fn test_suggest_next_authorities() {
+    let (a_sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let keys: Vec<AuthorityKeyPair> = (0..4).map(|_| get_key_pair().1).collect();
+    let names: Vec<AuthorityName> = keys
+        .iter()
+        .map(|k| AuthorityPublicKeyBytes::from(k.public()))
+        .collect();
+    // Total stake 10, quorum threshold 7.
+    let stakes = [1, 2, 3, 4];
+    let authorities: BTreeMap<_, _> = names.iter().cloned().zip(stakes).collect();
+    let committee = Committee::new(0, authorities).unwrap();
+
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            a_sender,
+            random_object_ref(),
+            a_sender,
+            random_object_ref(),
+            10000,
+        ),
+        &sender_sec,
+    );
+    let sign = |key: &AuthorityKeyPair| {
+        SignedTransaction::new(
+            committee.epoch(),
+            transaction.clone(),
+            AuthorityPublicKeyBytes::from(key.public()),
+            key,
+        )
+        .auth_sign_info
+    };
+
+    let mut builder = SignatureAggregator::try_new(transaction.clone(), &committee).unwrap();
+    let info = sign(&keys[0]);
+    assert!(builder
+        .append(info.authority, info.signature)
+        .unwrap()
+        .is_none());
+
+    // Without a preference, the heaviest unused authorities come first.
+    let suggested = builder.suggest_next_authorities(&[]);
+    assert_eq!(suggested, vec![names[3], names[2]]);
+    let weight: StakeUnit = suggested.iter().map(|n| committee.weight(n)).sum();
+    assert!(1 + weight >= committee.quorum_threshold());
+
+    // Preferred authorities are suggested first, and already used ones are skipped.
+    let suggested = builder.suggest_next_authorities(&[names[0], names[1]]);
+    assert_eq!(suggested, vec![names[1], names[3]]);
+
+    // Collecting the suggested signatures yields a certificate.
+    let mut cert = None;
+    for name in suggested {
+        let idx = names.iter().position(|n| *n == name).unwrap();
+        let info = sign(&keys[idx]);
+        cert = builder.append(info.authority, info.signature).unwrap();
+    }
+    assert!(cert.unwrap().verify(&committee).is_ok());
+    assert!(builder.suggest_next_authorities(&[]).is_empty());
+}
+
 #[test]
 fn test_new_with_signatures() {
     let message: Foo = Foo("some data".to_string());
@@ -699,3 +878,2426 @@ fn verify_sender_signature_correctly_with_flag() {
         .verify(&transaction.signed_data, &committee)
         .is_err());
 }
+
+#[test]
+fn test_execution_status_non_panicking_accessors() {
+    let success = ExecutionStatus::Success;
+    assert_eq!(success.ok(), Some(()));
+    assert_eq!(success.err(), None);
+
+    let failure = ExecutionStatus::new_failure(ExecutionFailureStatus::InsufficientGas);
+    assert_eq!(failure.ok(), None);
+    assert_eq!(
+        failure.err(),
+        Some(&ExecutionFailureStatus::InsufficientGas)
+    );
+}
+
+#[test]
+fn test_missing_input_objects() {
+    let present_object = Object::immutable_with_id_for_testing(ObjectID::random());
+    let present_kind = present_object.input_object_kind();
+    let missing_owned = InputObjectKind::ImmOrOwnedMoveObject(random_object_ref());
+    let missing_shared = InputObjectKind::SharedMoveObject(ObjectID::random());
+    let missing_package = InputObjectKind::MovePackage(ObjectID::random());
+
+    let present: BTreeMap<_, _> = [(present_object.id(), present_object)]
+        .into_iter()
+        .collect();
+    let kinds = vec![present_kind, missing_owned, missing_shared, missing_package];
+
+    assert_eq!(
+        missing_input_objects(&kinds, &present),
+        vec![missing_owned, missing_shared, missing_package]
+    );
+    assert!(missing_input_objects(&[present_kind], &present).is_empty());
+}
+
+#[test]
+fn test_input_objects_load() {
+    let sender = SuiAddress::random_for_testing_only();
+    let object = Object::with_owner_for_testing(sender);
+    let gas = Object::with_owner_for_testing(sender);
+    let data = TransactionData::new_transfer(
+        SuiAddress::random_for_testing_only(),
+        object.compute_object_reference(),
+        sender,
+        gas.compute_object_reference(),
+        10000,
+    );
+
+    let store: BTreeMap<_, _> = [(object.id(), object.clone()), (gas.id(), gas.clone())]
+        .into_iter()
+        .collect();
+    let input_objects = InputObjects::load(&data, |id| store.get(&id).cloned()).unwrap();
+    assert_eq!(input_objects.into_object_map(), store);
+
+    let incomplete: BTreeMap<_, _> = [(gas.id(), gas)].into_iter().collect();
+    assert_eq!(
+        InputObjects::load(&data, |id| incomplete.get(&id).cloned()).err(),
+        Some(SuiError::ObjectErrors {
+            errors: vec![SuiError::ObjectNotFound {
+                object_id: object.id()
+            }]
+        })
+    );
+}
+
+#[test]
+fn test_is_pure_sui_transfer() {
+    let sender = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+
+    let transfer_sui =
+        TransactionData::new_transfer_sui(recipient, sender, Some(100), random_object_ref(), 10000);
+    assert!(transfer_sui.is_pure_sui_transfer());
+
+    let pay = TransactionData::new_pay(
+        sender,
+        vec![random_object_ref()],
+        vec![recipient],
+        vec![100],
+        random_object_ref(),
+        10000,
+    );
+    assert!(!pay.is_pure_sui_transfer());
+
+    let batch = TransactionData::new(
+        TransactionKind::Batch(vec![SingleTransactionKind::TransferSui(TransferSui {
+            recipient,
+            amount: Some(100),
+        })]),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert!(!batch.is_pure_sui_transfer());
+}
+
+#[test]
+fn test_non_signing_authorities() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let cert = make_certificate_signed_by(&keys[..2], &committee);
+
+    let signers: Vec<AuthorityName> = keys[..2]
+        .iter()
+        .map(|key| AuthorityPublicKeyBytes::from(key.public()))
+        .collect();
+    let mut expected_signers = signers.clone();
+    expected_signers.sort();
+    assert_eq!(
+        cert.signing_authorities(&committee).unwrap(),
+        expected_signers
+    );
+
+    let non_signers = cert.non_signing_authorities(&committee);
+    assert_eq!(non_signers.len(), 2);
+    for key in &keys[2..] {
+        assert!(non_signers.contains(&AuthorityPublicKeyBytes::from(key.public())));
+    }
+    assert!(non_signers.iter().all(|name| !signers.contains(name)));
+}
+
+#[test]
+fn test_call_arg_canonical_bytes() {
+    // The same u64 value encoded by hand and via BCS must be canonically equal.
+    let by_hand = CallArg::Pure(42u64.to_le_bytes().to_vec());
+    let via_bcs = CallArg::Pure(bcs::to_bytes(&42u64).unwrap());
+    assert_eq!(by_hand.canonical_bytes(), via_bcs.canonical_bytes());
+    // Variant index, ULEB128 length, then the payload.
+    let mut expected = vec![0u8, 8];
+    expected.extend_from_slice(&42u64.to_le_bytes());
+    assert_eq!(by_hand.canonical_bytes(), expected);
+
+    // An object vector built from a literal or collected from an iterator encodes the same.
+    let id1 = ObjectID::random();
+    let id2 = ObjectID::random();
+    let literal = CallArg::ObjVec(vec![
+        ObjectArg::SharedObject(id1),
+        ObjectArg::SharedObject(id2),
+    ]);
+    let collected = CallArg::ObjVec(
+        [id1, id2]
+            .into_iter()
+            .map(ObjectArg::SharedObject)
+            .collect(),
+    );
+    assert_eq!(literal.canonical_bytes(), collected.canonical_bytes());
+
+    // ObjVec is prefixed by its variant index and element count only.
+    let bytes = collected.canonical_bytes();
+    assert_eq!(&bytes[..2], &[2u8, 2]);
+    let mut elements = bcs::to_bytes(&ObjectArg::SharedObject(id1)).unwrap();
+    elements.extend(bcs::to_bytes(&ObjectArg::SharedObject(id2)).unwrap());
+    assert_eq!(&bytes[2..], &elements[..]);
+
+    // Different values must not collide.
+    assert_ne!(
+        CallArg::Pure(bcs::to_bytes(&43u64).unwrap()).canonical_bytes(),
+        via_bcs.canonical_bytes()
+    );
+}
+
+#[test]
+fn test_transaction_effects_builder() {
+    let effects = TransactionEffectsBuilder::new().build();
+    assert!(effects.status.is_ok());
+    assert_eq!(effects.mutated, vec![effects.gas_object]);
+    assert_eq!(effects.mutated_excluding_gas().count(), 0);
+    assert!(effects.created.is_empty() && effects.deleted.is_empty());
+
+    let owner = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+    let gas_object = (random_object_ref(), owner);
+    let created = (random_object_ref(), owner);
+    let effects = TransactionEffectsBuilder::new()
+        .gas_object(gas_object)
+        .mutated(vec![gas_object])
+        .created(vec![created])
+        .deleted(vec![random_object_ref()])
+        .status(ExecutionStatus::new_failure(
+            ExecutionFailureStatus::InsufficientGas,
+        ))
+        .gas_used(GasCostSummary {
+            computation_cost: 10,
+            storage_cost: 5,
+            storage_rebate: 1,
+        })
+        .build();
+    // The gas object is not duplicated when it is already part of `mutated`.
+    assert_eq!(effects.mutated, vec![gas_object]);
+    assert_eq!(effects.created, vec![created]);
+    assert_eq!(effects.deleted.len(), 1);
+    assert!(effects.status.is_err());
+    assert_eq!(effects.gas_used.computation_cost, 10);
+
+    // No object appears in more than one of created, mutated and deleted.
+    let ids: Vec<_> = effects
+        .created
+        .iter()
+        .chain(effects.mutated.iter())
+        .map(|((id, _, _), _)| *id)
+        .chain(effects.deleted.iter().map(|(id, _, _)| *id))
+        .collect();
+    let unique: HashSet<_> = ids.iter().collect();
+    assert_eq!(ids.len(), unique.len());
+}
+
+#[test]
+fn test_verify_with_allowed_schemes() {
+    let sender_kp = SuiKeyPair::Secp256k1SuiKeyPair(get_key_pair().1);
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            SuiAddress::random_for_testing_only(),
+            random_object_ref(),
+            (&sender_kp.public()).into(),
+            random_object_ref(),
+            10000,
+        ),
+        &sender_kp,
+    );
+
+    assert!(transaction
+        .verify_with_allowed_schemes(&[SignatureScheme::ED25519, SignatureScheme::Secp256k1])
+        .is_ok());
+    assert_eq!(
+        transaction.verify_with_allowed_schemes(&[SignatureScheme::ED25519]),
+        Err(SuiError::SignatureSchemeNotAllowed {
+            scheme: "Secp256k1".to_string()
+        })
+    );
+    assert!(transaction.verify_with_allowed_schemes(&[]).is_err());
+}
+
+#[test]
+fn test_verify_sender_signatures() {
+    let transfer = |sender: SuiAddress, key: &AccountKeyPair| {
+        Transaction::from_data(
+            TransactionData::new_transfer(
+                SuiAddress::random_for_testing_only(),
+                random_object_ref(),
+                sender,
+                random_object_ref(),
+                10000,
+            ),
+            key,
+        )
+    };
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let (_, other_key): (_, AccountKeyPair) = get_key_pair();
+    let (_, authority_key): (_, AuthorityKeyPair) = get_key_pair();
+    // The sender signature of a system transaction is never checked.
+    let change_epoch = SignedTransaction::new_change_epoch(
+        1,
+        0,
+        0,
+        AuthorityPublicKeyBytes::from(authority_key.public()),
+        &authority_key,
+    )
+    .to_transaction();
+
+    let mut txns = vec![
+        transfer(sender, &sender_key),
+        change_epoch,
+        transfer(sender, &sender_key),
+    ];
+    assert!(verify_sender_signatures(&txns).is_ok());
+    assert!(verify_sender_signatures(&[]).is_ok());
+
+    txns.push(transfer(sender, &other_key));
+    assert!(matches!(
+        verify_sender_signatures(&txns),
+        Err(SuiError::InvalidSignature { .. })
+    ));
+}
+
+#[test]
+fn test_change_epoch_total_gas_reward() {
+    let change_epoch = |storage_charge, computation_charge| ChangeEpoch {
+        epoch: 1,
+        storage_charge,
+        computation_charge,
+    };
+    assert_eq!(change_epoch(100, 50).total_gas_reward(), 150);
+    assert_eq!(change_epoch(u64::MAX - 1, 1).total_gas_reward(), u64::MAX);
+    assert_eq!(change_epoch(u64::MAX, 1).total_gas_reward(), u64::MAX);
+    assert_eq!(
+        change_epoch(u64::MAX, u64::MAX).total_gas_reward(),
+        u64::MAX
+    );
+
+    let display = SingleTransactionKind::ChangeEpoch(change_epoch(100, 50)).to_string();
+    assert!(display.contains("Total gas reward: 150"));
+}
+
+#[test]
+fn test_transaction_data_from_intent() {
+    let sender = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+    let gas = random_object_ref();
+    let object = random_object_ref();
+    let intent = |kind: serde_json::Value| {
+        let mut intent = serde_json::json!({
+            "sender": sender,
+            "gas_payment": gas,
+            "gas_budget": 10000,
+        });
+        intent
+            .as_object_mut()
+            .unwrap()
+            .extend(kind.as_object().unwrap().clone());
+        TransactionData::from_intent(serde_json::from_value(intent).unwrap())
+    };
+
+    assert_eq!(
+        intent(serde_json::json!({
+            "kind": "TransferObject",
+            "recipient": recipient,
+            "object_ref": object,
+        }))
+        .unwrap(),
+        TransactionData::new_transfer(recipient, object, sender, gas, 10000)
+    );
+
+    assert_eq!(
+        intent(serde_json::json!({
+            "kind": "Pay",
+            "coins": [object],
+            "recipients": [recipient],
+            "amounts": [100],
+        }))
+        .unwrap(),
+        TransactionData::new_pay(sender, vec![object], vec![recipient], vec![100], gas, 10000)
+    );
+    assert!(matches!(
+        intent(serde_json::json!({
+            "kind": "Pay",
+            "coins": [object],
+            "recipients": [recipient],
+            "amounts": [],
+        })),
+        Err(SuiError::InvalidTransactionIntent { .. })
+    ));
+    assert!(matches!(
+        intent(serde_json::json!({
+            "kind": "Pay",
+            "coins": [],
+            "recipients": [recipient],
+            "amounts": [100],
+        })),
+        Err(SuiError::InvalidTransactionIntent { .. })
+    ));
+
+    let arguments = vec![CallArg::Pure(bcs::to_bytes(&100u64).unwrap())];
+    assert_eq!(
+        intent(serde_json::json!({
+            "kind": "MoveCall",
+            "package": object,
+            "module": "coin",
+            "function": "split",
+            "type_arguments": ["0x2::sui::SUI"],
+            "arguments": arguments,
+        }))
+        .unwrap(),
+        TransactionData::new_move_call(
+            sender,
+            object,
+            Identifier::new("coin").unwrap(),
+            Identifier::new("split").unwrap(),
+            vec![GasCoin::type_().type_params[0].clone()],
+            gas,
+            arguments,
+            10000,
+        )
+    );
+    assert!(matches!(
+        intent(serde_json::json!({
+            "kind": "MoveCall",
+            "package": object,
+            "module": "not a module",
+            "function": "split",
+        })),
+        Err(SuiError::InvalidTransactionIntent { .. })
+    ));
+    assert!(matches!(
+        intent(serde_json::json!({
+            "kind": "MoveCall",
+            "package": object,
+            "module": "coin",
+            "function": "split",
+            "type_arguments": ["0x2::sui::"],
+        })),
+        Err(SuiError::InvalidTransactionIntent { .. })
+    ));
+}
+
+#[test]
+fn test_try_new_module() {
+    let sender = SuiAddress::random_for_testing_only();
+    assert!(matches!(
+        TransactionData::try_new_module(sender, random_object_ref(), vec![], 10000),
+        Err(SuiError::ModulePublishFailure { .. })
+    ));
+
+    let modules = vec![vec![1, 2, 3]];
+    let data = TransactionData::try_new_module(sender, random_object_ref(), modules.clone(), 10000)
+        .unwrap();
+    assert_eq!(
+        data.kind,
+        TransactionKind::Single(SingleTransactionKind::Publish(MoveModulePublish {
+            modules
+        }))
+    );
+}
+
+#[test]
+fn test_package_upgrade() {
+    let sender = SuiAddress::random_for_testing_only();
+    let package = ObjectID::random();
+    let ticket = random_object_ref();
+    let data = TransactionData::new_upgrade(
+        sender,
+        random_object_ref(),
+        package,
+        vec![vec![1, 2, 3]],
+        ticket,
+        10000,
+    );
+    let upgrade = match &data.kind {
+        TransactionKind::Single(s) => s.clone(),
+        TransactionKind::Batch(_) => panic!("Expected a single transaction"),
+    };
+    assert_eq!(
+        upgrade.input_objects().unwrap(),
+        vec![
+            InputObjectKind::MovePackage(package),
+            InputObjectKind::ImmOrOwnedMoveObject(ticket),
+        ]
+    );
+    assert_eq!(data.min_created_bytes(), 3);
+    assert!(data.recipients().is_empty());
+
+    // Like publishing, upgrading cannot be batched.
+    assert!(data.kind.validity_check().is_ok());
+    assert!(TransactionKind::Batch(vec![upgrade.clone()])
+        .validity_check()
+        .is_err());
+
+    // No binary executes upgrades yet, so they are refused as soon as they are decoded.
+    assert_eq!(data.kind.required_version(), MAX_TRANSACTION_VERSION + 1);
+    assert_eq!(
+        TransactionKind::from_bytes_bounded(
+            &bcs::to_bytes(&data.kind).unwrap(),
+            DeserializeLimits::default()
+        ),
+        Err(SuiError::UnsupportedTransactionVersion {
+            required: MAX_TRANSACTION_VERSION + 1,
+            enabled: MAX_TRANSACTION_VERSION,
+        })
+    );
+
+    // Upgrades are appended after all existing kinds, so their BCS tags are unchanged.
+    let prologue = SingleTransactionKind::ConsensusCommitPrologue(ConsensusCommitPrologue {
+        round: 0,
+        commit_timestamp_ms: 0,
+    });
+    assert_eq!(bcs::to_bytes(&prologue).unwrap()[0], 6);
+    assert_eq!(bcs::to_bytes(&upgrade).unwrap()[0], 7);
+}
+
+#[test]
+fn test_consensus_commit_prologue() {
+    let prologue = SingleTransactionKind::ConsensusCommitPrologue(ConsensusCommitPrologue {
+        round: 7,
+        commit_timestamp_ms: 1_000,
+    });
+    assert_eq!(
+        prologue.input_objects().unwrap(),
+        vec![InputObjectKind::SharedMoveObject(
+            SUI_SYSTEM_STATE_OBJECT_ID
+        )]
+    );
+
+    // The prologue writes the system state during normal operation, so it is sequenced on it.
+    assert_eq!(
+        prologue
+            .shared_input_objects_with_mutability()
+            .collect::<Vec<_>>(),
+        vec![(&SUI_SYSTEM_STATE_OBJECT_ID, true)]
+    );
+
+    let kind = TransactionKind::Single(prologue.clone());
+    assert!(kind.is_system_tx());
+    assert!(!kind.is_change_epoch_tx());
+    assert_eq!(kind.required_version(), 2);
+    assert!(kind.validity_check().is_ok());
+    assert_eq!(
+        TransactionKind::Batch(vec![prologue.clone()]).validity_check(),
+        Err(SuiError::InvalidBatchTransaction {
+            error: BatchValidationError::ContainsSystemTx(0)
+        })
+    );
+
+    // The prologue is appended after all existing kinds, so their BCS tags are unchanged.
+    let change_epoch = SingleTransactionKind::ChangeEpoch(ChangeEpoch {
+        epoch: 0,
+        storage_charge: 0,
+        computation_charge: 0,
+    });
+    assert_eq!(bcs::to_bytes(&change_epoch).unwrap()[0], 5);
+    assert_eq!(bcs::to_bytes(&prologue).unwrap()[0], 6);
+    assert_eq!(change_epoch.required_version(), BASE_TRANSACTION_VERSION);
+}
+
+#[test]
+fn test_move_module_publish_module_names() {
+    let mut counter = file_format::empty_module();
+    counter.identifiers[0] = Identifier::new("counter").unwrap();
+    let mut registry = file_format::empty_module();
+    registry.identifiers[0] = Identifier::new("registry").unwrap();
+
+    let modules = [counter, registry]
+        .iter()
+        .map(|module| {
+            let mut bytes = Vec::new();
+            module.serialize(&mut bytes).unwrap();
+            bytes
+        })
+        .collect::<Vec<_>>();
+    let publish = MoveModulePublish {
+        modules: modules.clone(),
+    };
+    let expected = vec!["counter".to_string(), "registry".to_string()];
+    assert_eq!(publish.module_names(), expected);
+    assert_eq!(publish.try_module_names().unwrap(), expected);
+
+    // A malformed module is skipped by `module_names` and rejected by `try_module_names`.
+    let publish = MoveModulePublish {
+        modules: vec![modules[0].clone(), vec![0xde, 0xad, 0xbe, 0xef]],
+    };
+    assert_eq!(publish.module_names(), vec!["counter".to_string()]);
+    assert!(matches!(
+        publish.try_module_names(),
+        Err(SuiError::ModuleDeserializationFailure { .. })
+    ));
+}
+
+#[test]
+fn test_execution_failure_status_error_code() {
+    assert_eq!(
+        ExecutionFailureStatus::InsufficientGas.error_code(),
+        "InsufficientGas"
+    );
+    assert_eq!(
+        ExecutionFailureStatus::invalid_shared_by_value(ObjectID::random()).error_code(),
+        "InvalidSharedByValue"
+    );
+    assert_eq!(
+        ExecutionFailureStatus::InvalidParentFreezing {
+            parent: ObjectID::random()
+        }
+        .error_code(),
+        "InvalidParentFreezing"
+    );
+}
+
+#[test]
+fn test_execution_failure_status_in_command() {
+    let status = ExecutionFailureStatus::in_command(2, ExecutionFailureStatus::InsufficientGas);
+    assert_eq!(
+        status.to_string(),
+        "Command 2 of the batch failed. Insufficient Gas."
+    );
+
+    let bytes = bcs::to_bytes(&status).unwrap();
+    assert_eq!(
+        bcs::from_bytes::<ExecutionFailureStatus>(&bytes).unwrap(),
+        status
+    );
+    let json = serde_json::to_string(&status).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ExecutionFailureStatus>(&json).unwrap(),
+        status
+    );
+}
+
+#[test]
+fn test_transfer_object_without_public_transfer() {
+    let object = ObjectID::random();
+    let status = ExecutionFailureStatus::transfer_object_without_public_transfer(object);
+    assert_eq!(
+        status.to_string(),
+        format!(
+            "Invalid Transfer Object Transaction. Object {object} does not have public \
+            transfer, so it can only be transferred by a function of the module defining its type."
+        )
+    );
+    assert_eq!(status.error_code(), "TransferObjectWithoutPublicTransfer");
+
+    let bytes = bcs::to_bytes(&status).unwrap();
+    assert_eq!(
+        bcs::from_bytes::<ExecutionFailureStatus>(&bytes).unwrap(),
+        status
+    );
+    let json = serde_json::to_string(&status).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ExecutionFailureStatus>(&json).unwrap(),
+        status
+    );
+}
+
+#[test]
+fn test_precompute_digest() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let data = TransactionData::new_transfer_sui(
+        SuiAddress::random_for_testing_only(),
+        sender,
+        None,
+        random_object_ref(),
+        10000,
+    );
+    let transaction = Transaction::from_data(data, &sender_key);
+    assert!(transaction.transaction_digest.get().is_none());
+
+    transaction.precompute_digest();
+    let cached = *transaction.transaction_digest.get().unwrap();
+    assert_eq!(cached, *transaction.digest());
+
+    // Calling it again leaves the cached digest untouched.
+    transaction.precompute_digest();
+    assert_eq!(transaction.transaction_digest.get(), Some(&cached));
+}
+
+#[test]
+fn test_system_transaction_in_batch() {
+    let change_epoch = SingleTransactionKind::ChangeEpoch(ChangeEpoch {
+        epoch: 1,
+        storage_charge: 0,
+        computation_charge: 0,
+    });
+    let transfer = SingleTransactionKind::TransferObject(TransferObject {
+        recipient: SuiAddress::random_for_testing_only(),
+        object_ref: random_object_ref(),
+    });
+    assert!(TransactionKind::Single(change_epoch.clone())
+        .validity_check()
+        .is_ok());
+    assert!(TransactionKind::Batch(vec![transfer.clone()])
+        .validity_check()
+        .is_ok());
+
+    for (batch, index) in [
+        (vec![change_epoch.clone()], 0),
+        (vec![transfer.clone(), change_epoch.clone()], 1),
+    ] {
+        assert_eq!(
+            TransactionKind::Batch(batch).validity_check(),
+            Err(SuiError::InvalidBatchTransaction {
+                error: BatchValidationError::ContainsSystemTx(index)
+            })
+        );
+    }
+
+    // Other non-batchable kinds are reported as such.
+    let publish = SingleTransactionKind::Publish(MoveModulePublish { modules: vec![] });
+    assert_eq!(
+        TransactionKind::Batch(vec![transfer, publish]).validity_check(),
+        Err(SuiError::InvalidBatchTransaction {
+            error: BatchValidationError::ContainsNonBatchable(1)
+        })
+    );
+}
+
+#[test]
+fn test_min_created_bytes() {
+    let sender = SuiAddress::random_for_testing_only();
+    let publish = TransactionData::new_module(
+        sender,
+        random_object_ref(),
+        vec![vec![0; 100], vec![0; 28]],
+        10000,
+    );
+    assert_eq!(publish.min_created_bytes(), 128);
+
+    let transfer = TransactionData::new_transfer_sui(
+        SuiAddress::random_for_testing_only(),
+        sender,
+        None,
+        random_object_ref(),
+        10000,
+    );
+    assert_eq!(transfer.min_created_bytes(), 0);
+}
+
+#[test]
+fn test_transaction_recipients() {
+    let sender = SuiAddress::random_for_testing_only();
+    let a1 = SuiAddress::random_for_testing_only();
+    let a2 = SuiAddress::random_for_testing_only();
+    let a3 = SuiAddress::random_for_testing_only();
+
+    let batch = TransactionData::new(
+        TransactionKind::Batch(vec![
+            SingleTransactionKind::TransferObject(TransferObject {
+                recipient: a1,
+                object_ref: random_object_ref(),
+            }),
+            SingleTransactionKind::Pay(Pay {
+                coins: vec![random_object_ref()],
+                recipients: vec![a2, a3, a1],
+                amounts: vec![1, 2, 3],
+            }),
+        ]),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert_eq!(batch.recipients(), BTreeSet::from([a1, a2, a3]));
+
+    let publish = TransactionData::new_module(sender, random_object_ref(), vec![], 10000);
+    assert!(publish.recipients().is_empty());
+}
+
+#[test]
+fn test_batch_info_response_item_verify() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let names: Vec<AuthorityName> = keys.iter().map(|key| key.public().into()).collect();
+    let batch = AuthorityBatch::make_next(
+        &AuthorityBatch::initial(),
+        &[(0, ExecutionDigests::random())],
+    )
+    .unwrap();
+
+    let item = BatchInfoResponseItem(UpdateItem::Batch(SignedBatch::new(
+        committee.epoch,
+        batch.clone(),
+        &keys[0],
+        names[0],
+    )));
+    assert!(item.verify(&committee).is_ok());
+
+    // A signature attributed to a different authority does not verify.
+    let item = BatchInfoResponseItem(UpdateItem::Batch(SignedBatch::new(
+        committee.epoch,
+        batch.clone(),
+        &keys[0],
+        names[1],
+    )));
+    assert!(item.verify(&committee).is_err());
+
+    // Neither does a signature from outside the committee.
+    let (_, outsider): (_, AuthorityKeyPair) = get_key_pair();
+    let item = BatchInfoResponseItem(UpdateItem::Batch(SignedBatch::new(
+        committee.epoch,
+        batch,
+        &outsider,
+        outsider.public().into(),
+    )));
+    assert!(item.verify(&committee).is_err());
+
+    let item = BatchInfoResponseItem(UpdateItem::Transaction((0, ExecutionDigests::random())));
+    assert!(item.verify(&committee).is_ok());
+}
+
+#[test]
+fn test_validate_dependency_bound() {
+    let mut effects = TransactionEffectsBuilder::new().build();
+    assert!(effects.validate_dependency_bound().is_ok());
+
+    effects.dependencies = (0..MAX_DEPENDENCIES)
+        .map(|_| TransactionDigest::random())
+        .collect();
+    assert!(effects.validate_dependency_bound().is_ok());
+
+    effects.dependencies.push(TransactionDigest::random());
+    assert_eq!(
+        effects.validate_dependency_bound(),
+        Err(SuiError::TooManyTransactionDependencies {
+            count: MAX_DEPENDENCIES + 1,
+            max: MAX_DEPENDENCIES,
+        })
+    );
+}
+
+#[test]
+fn test_verification_cost_estimate() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let three_signers = make_certificate_signed_by(&keys[..3], &committee);
+    let four_signers = make_certificate_signed_by(&keys, &committee);
+    assert!(four_signers.verification_cost_estimate() > three_signers.verification_cost_estimate());
+    assert!(three_signers.verification_cost_estimate() > 0);
+}
+
+#[test]
+fn test_certificate_compact_bytes() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let mut certificate = make_certificate_signed_by(&keys[..3], &committee);
+    certificate.is_verified = true;
+
+    let bytes = certificate.to_compact_bytes();
+    let restored = CertifiedTransaction::from_compact_bytes(&bytes).unwrap();
+    assert!(!restored.is_verified);
+    assert_eq!(restored.digest(), certificate.digest());
+    assert_eq!(
+        bcs::to_bytes(&restored).unwrap(),
+        bcs::to_bytes(&certificate).unwrap()
+    );
+    assert_eq!(restored.to_compact_bytes(), bytes);
+    assert!(restored.verify(&committee).is_ok());
+
+    assert!(matches!(
+        CertifiedTransaction::from_compact_bytes(&bytes[..bytes.len() - 1]),
+        Err(SuiError::TransactionDeserializationError { .. })
+    ));
+}
+
+#[test]
+fn test_certificate_trusted_cache_bytes() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let (_, other_committee) = make_committee_key(&mut StdRng::from_seed([1; 32]));
+    let mut certificate = make_certificate_signed_by(&keys[..3], &committee);
+    assert!(certificate.verify(&other_committee).is_err());
+    certificate.is_verified = true;
+
+    let bytes = certificate.to_trusted_cache_bytes();
+    let restored = CertifiedTransaction::from_trusted_cache_bytes(&bytes).unwrap();
+    assert!(restored.is_verified);
+    assert_eq!(restored.digest(), certificate.digest());
+    assert_eq!(restored.to_compact_bytes(), certificate.to_compact_bytes());
+    // The flag is trusted, so verification is skipped: even the wrong committee passes.
+    assert!(restored.verify(&other_committee).is_ok());
+
+    // An unverified certificate stays unverified.
+    certificate.is_verified = false;
+    let restored =
+        CertifiedTransaction::from_trusted_cache_bytes(&certificate.to_trusted_cache_bytes())
+            .unwrap();
+    assert!(!restored.is_verified);
+    assert!(restored.verify(&other_committee).is_err());
+    assert!(restored.verify(&committee).is_ok());
+
+    // The compact format does not carry the flag and is not accepted here.
+    assert!(matches!(
+        CertifiedTransaction::from_trusted_cache_bytes(&certificate.to_compact_bytes()),
+        Err(SuiError::TransactionDeserializationError { .. })
+    ));
+}
+
+#[test]
+fn test_consensus_transaction_into_certificate() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let name: AuthorityName = keys[0].public().into();
+    let certificate = make_certificate_signed_by(&keys[..3], &committee);
+
+    let message = ConsensusTransaction::new_certificate_message(&name, certificate.clone());
+    let tracking_id = message.get_tracking_id();
+    let unwrapped = message.into_certificate().unwrap();
+    assert_eq!(
+        bcs::to_bytes(&unwrapped).unwrap(),
+        bcs::to_bytes(&certificate).unwrap()
+    );
+    // Wrapping the unwrapped certificate again yields the same tracking id.
+    assert_eq!(
+        ConsensusTransaction::new_certificate_message(&name, unwrapped).get_tracking_id(),
+        tracking_id
+    );
+
+    let proposal = |key: &AuthorityKeyPair| {
+        CheckpointProposal::new(
+            committee.epoch,
+            0,
+            key.public().into(),
+            key,
+            CheckpointProposalContents::new(std::iter::empty()),
+        )
+    };
+    let fragment = proposal(&keys[0]).fragment_with(&proposal(&keys[1]));
+    assert!(ConsensusTransaction::new_checkpoint_message(fragment)
+        .into_certificate()
+        .is_none());
+}
+
+#[test]
+fn test_transaction_kind_from_bytes_bounded() {
+    let limits = DeserializeLimits {
+        max_batch_size: 2,
+        max_pure_arg_length: 8,
+    };
+    let transfer = || {
+        SingleTransactionKind::TransferObject(TransferObject {
+            recipient: SuiAddress::random_for_testing_only(),
+            object_ref: random_object_ref(),
+        })
+    };
+    let call = |arg: Vec<u8>| {
+        SingleTransactionKind::Call(MoveCall {
+            package: random_object_ref(),
+            module: Identifier::new("m").unwrap(),
+            function: Identifier::new("f").unwrap(),
+            type_arguments: vec![],
+            arguments: vec![CallArg::Pure(arg)],
+        })
+    };
+
+    for kind in [
+        TransactionKind::Single(call(vec![0; 8])),
+        TransactionKind::Batch(vec![transfer(), call(vec![0; 8])]),
+    ] {
+        let bytes = bcs::to_bytes(&kind).unwrap();
+        assert_eq!(
+            TransactionKind::from_bytes_bounded(&bytes, limits).unwrap(),
+            kind
+        );
+    }
+
+    let oversized_batch = TransactionKind::Batch(vec![transfer(), transfer(), transfer()]);
+    let bytes = bcs::to_bytes(&oversized_batch).unwrap();
+    assert!(matches!(
+        TransactionKind::from_bytes_bounded(&bytes, limits),
+        Err(SuiError::TransactionDeserializationError { .. })
+    ));
+
+    let oversized_arg = TransactionKind::Single(call(vec![0; 9]));
+    let bytes = bcs::to_bytes(&oversized_arg).unwrap();
+    assert!(matches!(
+        TransactionKind::from_bytes_bounded(&bytes, limits),
+        Err(SuiError::TransactionDeserializationError { .. })
+    ));
+
+    // A batch header claiming u32::MAX entries with no entries following is rejected on the
+    // length prefix alone, rather than failing part-way through decoding.
+    let crafted = [1, 0xff, 0xff, 0xff, 0xff, 0x0f];
+    let err = TransactionKind::from_bytes_bounded(&crafted, limits).unwrap_err();
+    assert!(
+        matches!(&err, SuiError::TransactionDeserializationError { error } if error.contains("exceeds")),
+        "{err}"
+    );
+
+    assert!(TransactionKind::from_bytes_bounded(&[], limits).is_err());
+}
+
+#[test]
+fn test_receiving_object_arg() {
+    let received = random_object_ref();
+    let vec_received = random_object_ref();
+    let owned = random_object_ref();
+    let package = random_object_ref();
+    let call = SingleTransactionKind::Call(MoveCall {
+        package,
+        module: Identifier::new("m").unwrap(),
+        function: Identifier::new("f").unwrap(),
+        type_arguments: vec![],
+        arguments: vec![
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+            CallArg::Object(ObjectArg::Receiving(received)),
+            CallArg::ObjVec(vec![ObjectArg::Receiving(vec_received)]),
+        ],
+    });
+
+    assert_eq!(
+        call.input_objects().unwrap(),
+        vec![
+            InputObjectKind::ImmOrOwnedMoveObject(owned),
+            InputObjectKind::ImmOrOwnedMoveObject(received),
+            InputObjectKind::ImmOrOwnedMoveObject(vec_received),
+            InputObjectKind::MovePackage(package.0),
+        ]
+    );
+    assert_eq!(call.shared_input_objects().count(), 0);
+
+    // Receiving arguments need a newer transaction version, also inside a vector.
+    assert_eq!(call.required_version(), 2);
+    assert_eq!(
+        CallArg::ObjVec(vec![ObjectArg::Receiving(vec_received)]).required_version(),
+        2
+    );
+    assert_eq!(
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)).required_version(),
+        BASE_TRANSACTION_VERSION
+    );
+}
+
+#[test]
+fn test_readonly_shared_object_arg() {
+    let shared = ObjectID::random();
+    let readonly = ObjectID::random();
+    let vec_readonly = ObjectID::random();
+    let package = random_object_ref();
+    let call = SingleTransactionKind::Call(MoveCall {
+        package,
+        module: Identifier::new("m").unwrap(),
+        function: Identifier::new("f").unwrap(),
+        type_arguments: vec![],
+        arguments: vec![
+            CallArg::Object(ObjectArg::SharedObject(shared)),
+            CallArg::Object(ObjectArg::ReadonlySharedObject(readonly)),
+            CallArg::ObjVec(vec![ObjectArg::ReadonlySharedObject(vec_readonly)]),
+        ],
+    });
+
+    assert_eq!(
+        call.input_objects().unwrap(),
+        vec![
+            InputObjectKind::SharedMoveObject(shared),
+            InputObjectKind::ReadonlySharedMoveObject(readonly),
+            InputObjectKind::ReadonlySharedMoveObject(vec_readonly),
+            InputObjectKind::MovePackage(package.0),
+        ]
+    );
+    // Read-only shared objects are still sequenced, but reported as not mutable.
+    assert_eq!(
+        call.shared_input_objects().collect::<Vec<_>>(),
+        vec![&shared, &readonly, &vec_readonly]
+    );
+    assert_eq!(
+        call.shared_input_objects_with_mutability()
+            .collect::<Vec<_>>(),
+        vec![(&shared, true), (&readonly, false), (&vec_readonly, false)]
+    );
+    assert_eq!(call.required_version(), 2);
+}
+
+#[test]
+fn test_object_arg_variant_tags() {
+    // Appending variants to `ObjectArg` must not change the encoding of existing arguments, so
+    // that transactions signed before it was introduced keep their digests.
+    let object_ref = random_object_ref();
+    let tag = |arg: &ObjectArg| bcs::to_bytes(arg).unwrap()[0];
+    assert_eq!(tag(&ObjectArg::ImmOrOwnedObject(object_ref)), 0);
+    assert_eq!(tag(&ObjectArg::SharedObject(object_ref.0)), 1);
+    assert_eq!(tag(&ObjectArg::Receiving(object_ref)), 2);
+    assert_eq!(tag(&ObjectArg::ReadonlySharedObject(object_ref.0)), 3);
+    assert_eq!(
+        bcs::to_bytes(&ObjectArg::Receiving(object_ref)).unwrap()[1..],
+        bcs::to_bytes(&ObjectArg::ImmOrOwnedObject(object_ref)).unwrap()[1..]
+    );
+}
+
+#[test]
+fn test_transaction_gas_accessors() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let kind = TransactionKind::Single(SingleTransactionKind::TransferObject(TransferObject {
+        recipient: SuiAddress::random_for_testing_only(),
+        object_ref: random_object_ref(),
+    }));
+    let data = TransactionData::new_with_gas_price(kind, sender, random_object_ref(), 12345, 7);
+    let transaction = Transaction::from_data(data, &sender_key);
+    assert_eq!(transaction.gas_budget(), 12345);
+    assert_eq!(transaction.gas_price(), 7);
+
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let cert = make_certificate_signed_by(&keys[..3], &committee);
+    assert_eq!(cert.gas_budget(), 10000);
+    assert_eq!(cert.gas_price(), 1);
+}
+
+#[test]
+fn test_transactions_conflict() {
+    let sender = SuiAddress::random_for_testing_only();
+    let recipient = SuiAddress::random_for_testing_only();
+    let gas = random_object_ref();
+    let object = random_object_ref();
+
+    // Transfers paid with the same gas coin conflict.
+    let a = TransactionData::new_transfer(recipient, random_object_ref(), sender, gas, 10000);
+    let b = TransactionData::new_transfer(recipient, random_object_ref(), sender, gas, 10000);
+    assert!(transactions_conflict(&a, &b));
+
+    // As do transfers of the same object, even at different versions.
+    let next_version = (object.0, object.1.increment(), object.2);
+    let a = TransactionData::new_transfer(recipient, object, sender, random_object_ref(), 10000);
+    let b =
+        TransactionData::new_transfer(recipient, next_version, sender, random_object_ref(), 10000);
+    assert!(transactions_conflict(&a, &b));
+
+    // Transactions with disjoint owned inputs don't.
+    let a = TransactionData::new_transfer(recipient, object, sender, gas, 10000);
+    let b = TransactionData::new_transfer(
+        recipient,
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert!(!transactions_conflict(&a, &b));
+
+    // Neither do calls to the same package using the same shared object.
+    let shared = ObjectID::random();
+    let package = random_object_ref();
+    let call = |gas| {
+        TransactionData::new_move_call(
+            sender,
+            package,
+            Identifier::new("m").unwrap(),
+            Identifier::new("f").unwrap(),
+            vec![],
+            gas,
+            vec![CallArg::Object(ObjectArg::SharedObject(shared))],
+            10000,
+        )
+    };
+    assert!(!transactions_conflict(
+        &call(random_object_ref()),
+        &call(random_object_ref())
+    ));
+}
+
+#[test]
+fn test_shared_objects_with_mutability() {
+    let sender = SuiAddress::random_for_testing_only();
+    let (shared_1, shared_2) = (ObjectID::random(), ObjectID::random());
+    let readonly = ObjectID::random();
+    let call = |arguments| {
+        SingleTransactionKind::Call(MoveCall {
+            package: random_object_ref(),
+            module: Identifier::new("m").unwrap(),
+            function: Identifier::new("f").unwrap(),
+            type_arguments: vec![],
+            arguments,
+        })
+    };
+    let data = TransactionData::new(
+        TransactionKind::Batch(vec![
+            call(vec![
+                CallArg::Object(ObjectArg::SharedObject(shared_1)),
+                CallArg::Object(ObjectArg::ImmOrOwnedObject(random_object_ref())),
+                CallArg::Object(ObjectArg::ReadonlySharedObject(readonly)),
+            ]),
+            call(vec![CallArg::ObjVec(vec![ObjectArg::SharedObject(
+                shared_2,
+            )])]),
+        ]),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert_eq!(
+        data.shared_objects_with_mutability(),
+        vec![(shared_1, true), (readonly, false), (shared_2, true)]
+    );
+
+    let transfer = TransactionData::new_transfer(
+        sender,
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert!(transfer.shared_objects_with_mutability().is_empty());
+}
+
+#[test]
+fn test_touches_system_state() {
+    let sender = SuiAddress::random_for_testing_only();
+    let change_epoch = TransactionData::new(
+        TransactionKind::Single(SingleTransactionKind::ChangeEpoch(ChangeEpoch {
+            epoch: 1,
+            storage_charge: 0,
+            computation_charge: 0,
+        })),
+        SuiAddress::default(),
+        random_object_ref(),
+        0,
+    );
+    assert!(change_epoch.touches_system_state());
+
+    let transfer = TransactionData::new_transfer(
+        sender,
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert!(!transfer.touches_system_state());
+
+    let call = TransactionData::new_move_call(
+        sender,
+        random_object_ref(),
+        Identifier::new("sui_system").unwrap(),
+        Identifier::new("request_add_delegation").unwrap(),
+        vec![],
+        random_object_ref(),
+        vec![CallArg::Object(ObjectArg::SharedObject(
+            SUI_SYSTEM_STATE_OBJECT_ID,
+        ))],
+        10000,
+    );
+    assert!(call.touches_system_state());
+}
+
+#[test]
+fn test_input_objects_sorted() {
+    let sender = SuiAddress::random_for_testing_only();
+    let package = random_object_ref();
+    let gas = random_object_ref();
+    let owned = random_object_ref();
+    let shared = ObjectID::random();
+    let call = |arguments| {
+        TransactionData::new_move_call(
+            sender,
+            package,
+            Identifier::new("m").unwrap(),
+            Identifier::new("f").unwrap(),
+            vec![],
+            gas,
+            arguments,
+            10000,
+        )
+    };
+    let a = call(vec![
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+        CallArg::Pure(vec![1]),
+        CallArg::Object(ObjectArg::SharedObject(shared)),
+    ]);
+    let b = call(vec![
+        CallArg::Object(ObjectArg::SharedObject(shared)),
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+        CallArg::Pure(vec![1]),
+    ]);
+    assert_ne!(a.input_objects().unwrap(), b.input_objects().unwrap());
+
+    let sorted = a.input_objects_sorted().unwrap();
+    assert_eq!(sorted, b.input_objects_sorted().unwrap());
+    assert_eq!(sorted.len(), 4);
+    assert_eq!(
+        sorted.last(),
+        Some(&InputObjectKind::MovePackage(package.0))
+    );
+    let ids: Vec<_> = sorted[..3].iter().map(|kind| kind.object_id()).collect();
+    let mut expected = vec![gas.0, owned.0, shared];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    // Duplicate inputs are still rejected.
+    let duplicate = call(vec![
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+    ]);
+    assert!(duplicate.input_objects_sorted().is_err());
+}
+
+#[test]
+fn test_single_move_call() {
+    let sender = SuiAddress::random_for_testing_only();
+    let call = MoveCall {
+        package: random_object_ref(),
+        module: Identifier::new("m").unwrap(),
+        function: Identifier::new("f").unwrap(),
+        type_arguments: vec![],
+        arguments: vec![],
+    };
+    let data = |kind| TransactionData::new(kind, sender, random_object_ref(), 10000);
+
+    let single = data(TransactionKind::Single(SingleTransactionKind::Call(
+        call.clone(),
+    )));
+    assert_eq!(single.single_move_call(), Some(&call));
+
+    let batch = data(TransactionKind::Batch(vec![SingleTransactionKind::Call(
+        call.clone(),
+    )]));
+    assert_eq!(batch.single_move_call(), None);
+
+    let transfer = TransactionData::new_transfer(
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert_eq!(transfer.single_move_call(), None);
+}
+
+#[test]
+fn test_events_by_package() {
+    let package_a = ObjectID::random();
+    let package_b = ObjectID::random();
+    let sender = SuiAddress::random_for_testing_only();
+    let move_event = |defining_package: ObjectID, emitting_package| Event::MoveEvent {
+        package_id: emitting_package,
+        transaction_module: Identifier::new("m").unwrap(),
+        sender,
+        type_: move_core_types::language_storage::StructTag {
+            address: defining_package.into(),
+            module: Identifier::new("m").unwrap(),
+            name: Identifier::new("E").unwrap(),
+            type_params: vec![],
+        },
+        contents: vec![],
+    };
+    let new_object = Event::NewObject {
+        package_id: package_b,
+        transaction_module: Identifier::new("m").unwrap(),
+        sender,
+        recipient: Owner::AddressOwner(sender),
+        object_id: ObjectID::random(),
+    };
+
+    let mut effects = TransactionEffectsBuilder::new().build();
+    effects.events = vec![
+        move_event(package_a, package_a),
+        new_object.clone(),
+        Event::EpochChange(1),
+        // Grouped by the package defining the event type, not the one emitting it.
+        move_event(package_a, package_b),
+        move_event(package_b, package_b),
+    ];
+
+    let grouped = effects.events_by_package();
+    assert_eq!(grouped.len(), 2);
+    assert_eq!(
+        grouped[&package_a],
+        vec![&effects.events[0], &effects.events[3]]
+    );
+    assert_eq!(grouped[&package_b], vec![&new_object, &effects.events[4]]);
+}
+
+#[test]
+fn test_created_of_type() {
+    let coin = (
+        random_object_ref(),
+        Owner::AddressOwner(SuiAddress::default()),
+    );
+    let nft = (
+        random_object_ref(),
+        Owner::AddressOwner(SuiAddress::default()),
+    );
+    let unknown = (random_object_ref(), Owner::Immutable);
+    let effects = TransactionEffectsBuilder::new()
+        .created(vec![coin, nft, unknown])
+        .build();
+
+    let coin_type = TypeTag::Struct(GasCoin::type_());
+    let nft_type = TypeTag::Struct(move_core_types::language_storage::StructTag {
+        address: ObjectID::random().into(),
+        module: Identifier::new("nft").unwrap(),
+        name: Identifier::new("NFT").unwrap(),
+        type_params: vec![],
+    });
+    let types: BTreeMap<ObjectID, TypeTag> =
+        [(coin.0 .0, coin_type.clone()), (nft.0 .0, nft_type.clone())]
+            .into_iter()
+            .collect();
+    let resolver = |id: &ObjectID| types.get(id).cloned();
+
+    assert_eq!(effects.created_of_type(&nft_type, resolver), vec![&nft]);
+    assert_eq!(effects.created_of_type(&coin_type, resolver), vec![&coin]);
+    assert!(effects.created_of_type(&TypeTag::Bool, resolver).is_empty());
+}
+
+#[test]
+fn test_versioned_effects_bytes() {
+    let owner = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+    let effects = TransactionEffectsBuilder::new()
+        .created(vec![(random_object_ref(), owner)])
+        .build();
+
+    // Current effects round-trip through the tagged encoding.
+    let bytes = effects.to_versioned_bytes();
+    assert_eq!(
+        TransactionEffects::from_versioned_bytes(&bytes).unwrap(),
+        effects
+    );
+
+    // Records written before the `events` field load with no events.
+    let legacy = LegacyTransactionEffects {
+        status: ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InsufficientGas,
+        },
+        gas_used: effects.gas_used.clone(),
+        shared_objects: effects.shared_objects.clone(),
+        transaction_digest: effects.transaction_digest,
+        created: effects.created.clone(),
+        mutated: effects.mutated.clone(),
+        unwrapped: effects.unwrapped.clone(),
+        deleted: effects.deleted.clone(),
+        wrapped: effects.wrapped.clone(),
+        gas_object: effects.gas_object,
+        dependencies: vec![TransactionDigest::random()],
+    };
+    let legacy_bytes = bcs::to_bytes(&legacy).unwrap();
+    assert!(bcs::from_bytes::<TransactionEffects>(&legacy_bytes).is_err());
+    let loaded = TransactionEffects::from_versioned_bytes(&legacy_bytes).unwrap();
+    assert!(loaded.events.is_empty());
+    assert_eq!(loaded.status, legacy.status);
+    assert_eq!(loaded.created, effects.created);
+    assert_eq!(loaded.dependencies, legacy.dependencies);
+    let legacy_bytes = bcs::to_bytes(&LegacyTransactionEffects {
+        status: ExecutionStatus::Success,
+        ..legacy
+    })
+    .unwrap();
+    assert!(TransactionEffects::from_versioned_bytes(&legacy_bytes)
+        .unwrap()
+        .status
+        .is_ok());
+
+    assert!(TransactionEffects::from_versioned_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_effects_digest_from_bytes() {
+    let effects = TransactionEffectsBuilder::new()
+        .created(vec![(
+            random_object_ref(),
+            Owner::AddressOwner(SuiAddress::default()),
+        )])
+        .build();
+    let bytes = bcs::to_bytes(&effects).unwrap();
+    assert_eq!(effects_digest_from_bytes(&bytes).unwrap(), effects.digest());
+
+    assert!(matches!(
+        effects_digest_from_bytes(&bytes[..bytes.len() - 1]),
+        Err(SuiError::TransactionEffectsDeserializationError { .. })
+    ));
+}
+
+#[test]
+fn test_sum_gas_summaries() {
+    let effects: Vec<_> = [(10, 20, 5), (1, 2, 3), (100, 0, 7)]
+        .into_iter()
+        .map(|(computation_cost, storage_cost, storage_rebate)| {
+            TransactionEffectsBuilder::new()
+                .gas_used(GasCostSummary {
+                    computation_cost,
+                    storage_cost,
+                    storage_rebate,
+                })
+                .build()
+        })
+        .collect();
+    assert_eq!(
+        sum_gas_summaries(&effects),
+        GasCostSummary {
+            computation_cost: 111,
+            storage_cost: 22,
+            storage_rebate: 15,
+        }
+    );
+    assert_eq!(sum_gas_summaries(&effects[..0]).gas_used(), 0);
+
+    // Totals saturate instead of overflowing.
+    let large = TransactionEffectsBuilder::new()
+        .gas_used(GasCostSummary {
+            computation_cost: u64::MAX,
+            storage_cost: 1,
+            storage_rebate: 0,
+        })
+        .build();
+    let total = sum_gas_summaries([&large, &large]);
+    assert_eq!(total.computation_cost, u64::MAX);
+    assert_eq!(total.storage_cost, 2);
+}
+
+#[test]
+fn test_removed_object_refs() {
+    let deleted = vec![random_object_ref(), random_object_ref()];
+    let wrapped = random_object_ref();
+    let mut effects = TransactionEffectsBuilder::new()
+        .deleted(deleted.clone())
+        .build();
+    assert_eq!(effects.removed_object_refs(), deleted);
+
+    // A reference listed twice is only returned once.
+    effects.wrapped = vec![wrapped, deleted[0]];
+    assert_eq!(
+        effects.removed_object_refs(),
+        vec![deleted[0], deleted[1], wrapped]
+    );
+}
+
+#[test]
+fn test_signed_effects_equal() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let effects = TransactionEffectsBuilder::new().build();
+    let sign = |effects: TransactionEffects, key: &AuthorityKeyPair| {
+        effects.to_sign_effects(committee.epoch(), &key.public().into(), key)
+    };
+
+    let signed_0 = sign(effects.clone(), &keys[0]);
+    let signed_1 = sign(effects.clone(), &keys[1]);
+    assert!(signed_0 != signed_1);
+    assert!(signed_0.effects_equal(&signed_1));
+
+    let mut other_effects = effects;
+    other_effects.status = ExecutionStatus::new_failure(ExecutionFailureStatus::InsufficientGas);
+    assert!(!signed_0.effects_equal(&sign(other_effects, &keys[1])));
+}
+
+#[test]
+fn test_is_from_committee() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            SuiAddress::random_for_testing_only(),
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            10000,
+        ),
+        &sender_key,
+    );
+    let sign = |epoch, key: &AuthorityKeyPair| {
+        SignedTransaction::new(epoch, transaction.clone(), key.public().into(), key)
+    };
+
+    assert!(sign(committee.epoch(), &keys[0]).is_from_committee(&committee));
+    assert!(!sign(committee.epoch() + 1, &keys[0]).is_from_committee(&committee));
+
+    let (_, unknown_key): (_, AuthorityKeyPair) = get_key_pair();
+    assert!(!sign(committee.epoch(), &unknown_key).is_from_committee(&committee));
+}
+
+#[test]
+fn test_correlate() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            SuiAddress::random_for_testing_only(),
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            10000,
+        ),
+        &sender_key,
+    );
+    let signed_tx = SignedTransaction::new(
+        committee.epoch(),
+        transaction,
+        keys[0].public().into(),
+        &keys[0],
+    );
+    let sign = |effects: TransactionEffects| {
+        effects.to_sign_effects(committee.epoch(), &keys[0].public().into(), &keys[0])
+    };
+
+    let effects = TransactionEffectsBuilder::new()
+        .transaction_digest(*signed_tx.digest())
+        .build();
+    assert!(correlate(&signed_tx, &sign(effects)));
+
+    let other_effects = TransactionEffectsBuilder::new().build();
+    assert!(!correlate(&signed_tx, &sign(other_effects)));
+}
+
+#[test]
+fn test_effects_is_noop() {
+    let owner = Owner::AddressOwner(SuiAddress::default());
+    assert!(TransactionEffectsBuilder::new().build().is_noop());
+
+    let effects = TransactionEffectsBuilder::new()
+        .created(vec![(random_object_ref(), owner)])
+        .build();
+    assert!(!effects.is_noop());
+
+    let effects = TransactionEffectsBuilder::new()
+        .mutated(vec![(random_object_ref(), owner)])
+        .build();
+    assert!(!effects.is_noop());
+
+    let effects = TransactionEffectsBuilder::new()
+        .deleted(vec![random_object_ref()])
+        .build();
+    assert!(!effects.is_noop());
+}
+
+#[test]
+fn test_certificate_authorities_ordering() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let names = committee.ordered_names();
+    let mut sorted = names.clone();
+    sorted.sort();
+    assert_eq!(names, sorted);
+
+    let signers = [&keys[3], &keys[1], &keys[0]];
+    let signer_keys: Vec<_> = signers.iter().map(|key| key.copy()).collect();
+    let mut cert = make_certificate_signed_by(&signer_keys, &committee);
+    let signing = cert.signing_authorities(&committee).unwrap();
+    let non_signing = cert.non_signing_authorities(&committee);
+
+    // Both are subsequences of the committee order, and together cover the committee.
+    let signer_names: BTreeSet<AuthorityName> = signers
+        .iter()
+        .map(|key| AuthorityPublicKeyBytes::from(key.public()))
+        .collect();
+    assert_eq!(
+        signing,
+        names
+            .iter()
+            .filter(|name| signer_names.contains(name))
+            .copied()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        non_signing,
+        names
+            .iter()
+            .filter(|name| !signer_names.contains(name))
+            .copied()
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        cert.signed_weight(&committee).unwrap(),
+        signing
+            .iter()
+            .map(|name| committee.weight(name))
+            .sum::<u64>()
+    );
+    assert_eq!(
+        cert.signed_weight(&committee).unwrap()
+            + non_signing
+                .iter()
+                .map(|name| committee.weight(name))
+                .sum::<u64>(),
+        committee.total_votes
+    );
+
+    // A bitmap referring past the end of the committee is rejected.
+    cert.auth_sign_info
+        .signers_map
+        .insert(committee.num_members() as u32);
+    assert!(cert.signing_authorities(&committee).is_err());
+    assert!(cert.signed_weight(&committee).is_err());
+}
+
+#[test]
+fn test_pay_duplicate_recipients() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let (a, _): (_, AccountKeyPair) = get_key_pair();
+    let (b, _): (_, AccountKeyPair) = get_key_pair();
+    let coins = vec![random_object_ref()];
+
+    let unique = Pay {
+        coins: coins.clone(),
+        recipients: vec![a, b],
+        amounts: vec![1, 2],
+    };
+    assert!(!unique.has_duplicate_recipients());
+    assert!(TransactionData::try_new_pay(
+        sender,
+        coins.clone(),
+        vec![a, b],
+        vec![1, 2],
+        random_object_ref(),
+        10000
+    )
+    .is_ok());
+
+    let duplicate = Pay {
+        coins: coins.clone(),
+        recipients: vec![a, b, a],
+        amounts: vec![1, 2, 3],
+    };
+    assert!(duplicate.has_duplicate_recipients());
+    assert!(matches!(
+        TransactionData::try_new_pay(
+            sender,
+            coins,
+            vec![a, b, a],
+            vec![1, 2, 3],
+            random_object_ref(),
+            10000
+        ),
+        Err(SuiError::DuplicatePayRecipient { recipient }) if recipient == a
+    ));
+}
+
+#[test]
+fn test_signer_difference() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let transaction = Transaction::from_data(
+        TransactionData::new_transfer(
+            sender,
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            10000,
+        ),
+        &sender_sec,
+    );
+    let certify = |keys: &[AuthorityKeyPair]| {
+        let signatures = keys
+            .iter()
+            .map(|key| {
+                (
+                    AuthorityPublicKeyBytes::from(key.public()),
+                    AuthoritySignature::new(&transaction.signed_data, key),
+                )
+            })
+            .collect();
+        CertifiedTransaction::new_with_signatures(transaction.clone(), signatures, &committee)
+            .unwrap()
+    };
+    let name = |key: &AuthorityKeyPair| AuthorityPublicKeyBytes::from(key.public());
+
+    // Overlapping signer sets: the first three and the last three authorities.
+    let first = certify(&keys[..3]);
+    let second = certify(&keys[1..]);
+    assert_eq!(
+        first.signer_difference(&second, &committee),
+        (vec![name(&keys[0])], vec![name(&keys[3])])
+    );
+    assert_eq!(
+        second.signer_difference(&first, &committee),
+        (vec![name(&keys[3])], vec![name(&keys[0])])
+    );
+
+    // Identical signer sets have no difference.
+    assert_eq!(
+        first.signer_difference(&first, &committee),
+        (vec![], vec![])
+    );
+}
+
+#[test]
+fn test_received_coin_by() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+
+    // A pay to `recipient`: one new coin, one existing coin transferred, the change kept by
+    // the sender, and an NFT also sent to the recipient.
+    let new_coin = (random_object_ref(), Owner::AddressOwner(recipient));
+    let transferred_coin = (random_object_ref(), Owner::AddressOwner(recipient));
+    let change = (random_object_ref(), Owner::AddressOwner(sender));
+    let nft = (random_object_ref(), Owner::AddressOwner(recipient));
+    let gas = (random_object_ref(), Owner::AddressOwner(sender));
+    let effects = TransactionEffectsBuilder::new()
+        .created(vec![new_coin, nft])
+        .mutated(vec![transferred_coin, change])
+        .gas_object(gas)
+        .build();
+
+    let coins: BTreeSet<ObjectID> = [new_coin, transferred_coin, change, gas]
+        .iter()
+        .map(|((id, _, _), _)| *id)
+        .collect();
+    let resolver = |id: &ObjectID| coins.contains(id);
+
+    assert_eq!(
+        effects.received_coin_by(recipient, resolver),
+        vec![new_coin.0, transferred_coin.0]
+    );
+    // The gas object is never reported as received.
+    assert_eq!(effects.received_coin_by(sender, resolver), vec![change.0]);
+
+    let failed = TransactionEffectsBuilder::new()
+        .created(vec![new_coin])
+        .status(ExecutionStatus::new_failure(
+            ExecutionFailureStatus::InsufficientGas,
+        ))
+        .build();
+    assert!(failed.received_coin_by(recipient, resolver).is_empty());
+}
+
+#[test]
+fn test_move_module_publish_try_from_reader() {
+    let limits = PublishLimits {
+        max_module_size: 8,
+        max_total_size: 12,
+    };
+
+    let package = MoveModulePublish {
+        modules: vec![vec![1; 8], vec![2; 4]],
+    };
+    let bytes = bcs::to_bytes(&package).unwrap();
+    assert_eq!(
+        MoveModulePublish::try_from_reader(bytes.as_slice(), limits).unwrap(),
+        package
+    );
+
+    let oversized_module = MoveModulePublish {
+        modules: vec![vec![1; 9]],
+    };
+    let bytes = bcs::to_bytes(&oversized_module).unwrap();
+    assert!(matches!(
+        MoveModulePublish::try_from_reader(bytes.as_slice(), limits),
+        Err(SuiError::TransactionDeserializationError { error }) if error.contains("exceeds")
+    ));
+
+    let oversized_package = MoveModulePublish {
+        modules: vec![vec![1; 8], vec![2; 8]],
+    };
+    let bytes = bcs::to_bytes(&oversized_package).unwrap();
+    assert!(MoveModulePublish::try_from_reader(bytes.as_slice(), limits).is_err());
+
+    // A module claiming u32::MAX bytes with nothing following is rejected on the length
+    // prefix alone, before anything is buffered.
+    let crafted = [1, 0xff, 0xff, 0xff, 0xff, 0x0f];
+    assert!(matches!(
+        MoveModulePublish::try_from_reader(crafted.as_slice(), limits),
+        Err(SuiError::TransactionDeserializationError { error }) if error.contains("exceeds")
+    ));
+
+    // Truncated input.
+    assert!(MoveModulePublish::try_from_reader(&bytes[..4], limits).is_err());
+}
+
+#[test]
+fn test_all_call_args() {
+    let call = |arguments: Vec<CallArg>| {
+        SingleTransactionKind::Call(MoveCall {
+            package: random_object_ref(),
+            module: Identifier::new("m").unwrap(),
+            function: Identifier::new("f").unwrap(),
+            type_arguments: vec![],
+            arguments,
+        })
+    };
+    let first = vec![
+        CallArg::Pure(vec![1]),
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(random_object_ref())),
+    ];
+    let second = vec![
+        CallArg::Object(ObjectArg::SharedObject(ObjectID::random())),
+        CallArg::Pure(vec![2]),
+    ];
+    let data = TransactionData::new(
+        TransactionKind::Batch(vec![
+            call(first.clone()),
+            SingleTransactionKind::TransferSui(TransferSui {
+                recipient: SuiAddress::random_for_testing_only(),
+                amount: None,
+            }),
+            call(second.clone()),
+        ]),
+        SuiAddress::random_for_testing_only(),
+        random_object_ref(),
+        10000,
+    );
+
+    let expected: Vec<&CallArg> = first.iter().chain(second.iter()).collect();
+    assert_eq!(data.all_call_args(), expected);
+}
+
+#[test]
+fn test_certificate_matches_effects() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let cert = make_certificate_signed_by(&keys[..3], &committee);
+    let sign = |effects: TransactionEffects| {
+        effects.to_sign_effects(committee.epoch(), &keys[0].public().into(), &keys[0])
+    };
+
+    let effects = TransactionEffectsBuilder::new()
+        .transaction_digest(*cert.digest())
+        .build();
+    assert!(cert.matches_effects(&sign(effects)));
+
+    let other_effects = TransactionEffectsBuilder::new().build();
+    assert!(!cert.matches_effects(&sign(other_effects)));
+}
+
+#[test]
+fn test_labeled_transaction() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let transfer = |recipient| {
+        SingleTransactionKind::TransferSui(TransferSui {
+            recipient,
+            amount: Some(1),
+        })
+    };
+    let data = TransactionData::new(
+        TransactionKind::Batch(vec![
+            transfer(SuiAddress::random_for_testing_only()),
+            transfer(SuiAddress::random_for_testing_only()),
+        ]),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+
+    let labeled =
+        LabeledTransaction::new(data.clone(), vec![Some("Pay rent".to_string()), None]).unwrap();
+    assert_eq!(labeled.label(0), Some("Pay rent"));
+    assert_eq!(labeled.label(1), None);
+    assert_eq!(labeled.label(2), None);
+    assert_eq!(
+        labeled
+            .labeled_commands()
+            .map(|(_, label)| label)
+            .collect::<Vec<_>>(),
+        vec![Some("Pay rent"), None]
+    );
+
+    // Batches differing only in their labels sign to the same transaction.
+    let relabeled = LabeledTransaction::new(
+        data.clone(),
+        vec![Some("Step 1".to_string()), Some("Step 2".to_string())],
+    )
+    .unwrap();
+    let tx = Transaction::from_data(labeled.data.clone(), &sender_key);
+    let other_tx = Transaction::from_data(relabeled.data.clone(), &sender_key);
+    assert_eq!(tx.digest(), other_tx.digest());
+    assert!(other_tx.verify().is_ok());
+
+    assert_eq!(
+        LabeledTransaction::new(data, vec![None]).unwrap_err(),
+        SuiError::CommandLabelCountMismatch {
+            expected: 2,
+            actual: 1
+        }
+    );
+}
+
+#[test]
+fn test_input_object_kind_requires_version_resolution() {
+    let owned = random_object_ref();
+    assert!(!InputObjectKind::ImmOrOwnedMoveObject(owned).requires_version_resolution());
+    assert_eq!(
+        InputObjectKind::ImmOrOwnedMoveObject(owned).version(),
+        owned.1
+    );
+    assert!(!InputObjectKind::MovePackage(ObjectID::random()).requires_version_resolution());
+
+    let shared = ObjectID::random();
+    assert!(InputObjectKind::SharedMoveObject(shared).requires_version_resolution());
+    assert!(InputObjectKind::ReadonlySharedMoveObject(shared).requires_version_resolution());
+}
+
+#[test]
+fn test_transaction_info_response_from_effects() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let effects = TransactionEffectsBuilder::new().build().to_sign_effects(
+        committee.epoch(),
+        &keys[0].public().into(),
+        &keys[0],
+    );
+
+    let response = TransactionInfoResponse::from_effects(effects.clone());
+    assert!(response.signed_transaction.is_none());
+    assert!(response.certified_transaction.is_none());
+    assert_eq!(response.signed_effects, Some(effects));
+}
+
+#[test]
+fn test_input_objects_readonly_inputs() {
+    let package = Object::new_package(
+        vec![file_format::empty_module()],
+        TransactionDigest::genesis(),
+    );
+    let immutable = Object::immutable_with_id_for_testing(ObjectID::random());
+    let owned = Object::with_owner_for_testing(SuiAddress::random_for_testing_only());
+    let mut shared = Object::with_owner_for_testing(SuiAddress::random_for_testing_only());
+    shared.owner = Owner::Shared;
+    let mut readonly_shared = Object::with_owner_for_testing(SuiAddress::random_for_testing_only());
+    readonly_shared.owner = Owner::Shared;
+    let input_objects = InputObjects::new(vec![
+        (InputObjectKind::MovePackage(package.id()), package.clone()),
+        (
+            InputObjectKind::ImmOrOwnedMoveObject(immutable.compute_object_reference()),
+            immutable.clone(),
+        ),
+        (
+            InputObjectKind::ImmOrOwnedMoveObject(owned.compute_object_reference()),
+            owned.clone(),
+        ),
+        (
+            InputObjectKind::SharedMoveObject(shared.id()),
+            shared.clone(),
+        ),
+        (
+            InputObjectKind::ReadonlySharedMoveObject(readonly_shared.id()),
+            readonly_shared.clone(),
+        ),
+    ]);
+
+    // Read-only shared objects are never written, so their version is not bumped.
+    assert_eq!(
+        input_objects.readonly_inputs(),
+        vec![
+            package.compute_object_reference(),
+            immutable.compute_object_reference(),
+            readonly_shared.compute_object_reference(),
+        ]
+    );
+    assert_eq!(
+        input_objects.mutable_inputs(),
+        vec![
+            owned.compute_object_reference(),
+            shared.compute_object_reference()
+        ]
+    );
+}
+
+#[test]
+fn test_transaction_kind_structurally_equal() {
+    let recipient = dbg_addr(1);
+    let transfer = |object_ref| {
+        TransactionKind::Single(SingleTransactionKind::TransferObject(TransferObject {
+            recipient,
+            object_ref,
+        }))
+    };
+
+    let (id, version, _) = random_object_ref();
+    let before = transfer((id, version, ObjectDigest::random()));
+    let after = transfer((id, version.increment(), ObjectDigest::random()));
+    assert!(before.structurally_equal(&after));
+    assert_ne!(before, after);
+
+    let other = transfer(random_object_ref());
+    assert!(!before.structurally_equal(&other));
+}
+
+#[test]
+fn test_transaction_data_try_new_rejects_invalid_batch() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let gas = random_object_ref();
+
+    let empty = TransactionKind::Batch(vec![]);
+    assert!(matches!(
+        TransactionData::try_new(empty, sender, gas, 10000),
+        Err(SuiError::InvalidBatchTransaction { .. })
+    ));
+
+    let with_publish = TransactionKind::Batch(vec![
+        SingleTransactionKind::TransferObject(TransferObject {
+            recipient: dbg_addr(1),
+            object_ref: random_object_ref(),
+        }),
+        SingleTransactionKind::Publish(MoveModulePublish {
+            modules: vec![vec![1, 2, 3]],
+        }),
+    ]);
+    assert!(matches!(
+        TransactionData::try_new(with_publish, sender, gas, 10000),
+        Err(SuiError::InvalidBatchTransaction { .. })
+    ));
+
+    let single = TransactionKind::Single(SingleTransactionKind::TransferObject(TransferObject {
+        recipient: dbg_addr(1),
+        object_ref: random_object_ref(),
+    }));
+    assert!(TransactionData::try_new(single, sender, gas, 10000).is_ok());
+}
+
+#[test]
+fn test_consensus_transaction_checkpoint_participants() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let proposal = |key: &AuthorityKeyPair| {
+        CheckpointProposal::new(
+            committee.epoch,
+            0,
+            key.public().into(),
+            key,
+            CheckpointProposalContents::new(std::iter::empty()),
+        )
+    };
+    let fragment = proposal(&keys[0]).fragment_with(&proposal(&keys[1]));
+    let message = ConsensusTransaction::new_checkpoint_message(fragment);
+    assert_eq!(
+        message.checkpoint_participants(),
+        Some((keys[0].public().into(), keys[1].public().into()))
+    );
+
+    let certificate = make_certificate_signed_by(&keys[..3], &committee);
+    let message =
+        ConsensusTransaction::new_certificate_message(&keys[0].public().into(), certificate);
+    assert_eq!(message.checkpoint_participants(), None);
+}
+
+#[test]
+fn test_check_gas_budget_ceiling() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let data = TransactionData::new_transfer_sui(sender, sender, None, random_object_ref(), 10000);
+
+    assert!(data.check_gas_budget_ceiling(10001).is_ok());
+    assert!(data.check_gas_budget_ceiling(10000).is_ok());
+    assert!(matches!(
+        data.check_gas_budget_ceiling(9999),
+        Err(SuiError::GasBudgetTooHigh { .. })
+    ));
+}
+
+#[test]
+fn test_transaction_data_digest() {
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let (_, other_sec): (SuiAddress, AccountKeyPair) = get_key_pair();
+    let data = TransactionData::new_transfer(
+        dbg_addr(1),
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+
+    let transaction = Transaction::from_data(data.clone(), &sender_sec);
+    let other = Transaction::from_data(data.clone(), &other_sec);
+    // The envelope digest covers the signature, the data digest does not.
+    assert_ne!(transaction.digest(), other.digest());
+    assert_eq!(transaction.signed_data.data.digest(), data.digest());
+    assert_eq!(other.signed_data.data.digest(), data.digest());
+    assert_ne!(&data.digest(), transaction.digest());
+}
+
+#[test]
+fn test_committee_info_response_reference_gas_price() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let response = CommitteeInfoResponse {
+        epoch: committee.epoch,
+        committee_info: Some(vec![(keys[0].public().into(), 1)]),
+        reference_gas_price: Some(7),
+    };
+    let without_price = CommitteeInfoResponse {
+        reference_gas_price: None,
+        ..response.clone()
+    };
+
+    for response in [&response, &without_price] {
+        let bytes = bcs::to_bytes(response).unwrap();
+        let restored: CommitteeInfoResponse = bcs::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.reference_gas_price, response.reference_gas_price);
+        assert_eq!(restored.digest(), response.digest());
+
+        let json = serde_json::to_string(response).unwrap();
+        let restored: CommitteeInfoResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.reference_gas_price, response.reference_gas_price);
+    }
+    // The digest covers the reference gas price.
+    assert_ne!(response.digest(), without_price.digest());
+
+    // Responses from authorities that predate the field have no reference gas price.
+    let restored: CommitteeInfoResponse =
+        serde_json::from_str(r#"{"epoch":0,"committee_info":null}"#).unwrap();
+    assert_eq!(restored.reference_gas_price, None);
+}
+
+#[test]
+fn test_legacy_committee_info_response() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let response = CommitteeInfoResponse {
+        epoch: committee.epoch,
+        committee_info: Some(vec![(keys[0].public().into(), 1)]),
+        reference_gas_price: Some(7),
+    };
+    // The encoding of the response before it carried the reference gas price.
+    let old_fields = (response.epoch, response.committee_info.clone());
+    let old_bytes = bincode::serialize(&old_fields).unwrap();
+
+    // The legacy route keeps serving exactly those bytes to existing clients.
+    let legacy = LegacyCommitteeInfoResponse::from(response.clone());
+    assert_eq!(bincode::serialize(&legacy).unwrap(), old_bytes);
+
+    // A response from an authority that predates the field decodes without a price.
+    let restored: LegacyCommitteeInfoResponse = bincode::deserialize(&old_bytes).unwrap();
+    let restored = CommitteeInfoResponse::from(restored);
+    assert_eq!(restored.epoch, response.epoch);
+    assert_eq!(restored.committee_info, response.committee_info);
+    assert_eq!(restored.reference_gas_price, None);
+
+    // The new layout cannot be decoded from the old bytes, hence the separate route.
+    assert!(bincode::deserialize::<CommitteeInfoResponse>(&old_bytes).is_err());
+}
+
+#[test]
+fn test_validate_gas_not_in_inputs() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let gas = random_object_ref();
+    let transfer = |object_ref| {
+        SingleTransactionKind::TransferObject(TransferObject {
+            recipient: dbg_addr(1),
+            object_ref,
+        })
+    };
+
+    let data = TransactionData::new(
+        TransactionKind::Batch(vec![
+            transfer(random_object_ref()),
+            transfer(random_object_ref()),
+        ]),
+        sender,
+        gas,
+        10000,
+    );
+    assert!(data.validate_gas_not_in_inputs().is_ok());
+
+    // The gas coin is transferred by the second sub-transaction.
+    let data = TransactionData::new(
+        TransactionKind::Batch(vec![transfer(random_object_ref()), transfer(gas)]),
+        sender,
+        gas,
+        10000,
+    );
+    assert_eq!(
+        data.validate_gas_not_in_inputs(),
+        Err(SuiError::GasObjectInInputs { object_id: gas.0 })
+    );
+
+    // Paying out of the gas coin is a conflict too.
+    let data = TransactionData::new_pay(sender, vec![gas], vec![dbg_addr(1)], vec![1], gas, 10000);
+    assert!(data.validate_gas_not_in_inputs().is_err());
+
+    let data = TransactionData::new_transfer_sui(dbg_addr(1), sender, Some(1), gas, 10000);
+    assert!(data.validate_gas_not_in_inputs().is_ok());
+}
+
+#[test]
+fn test_is_owned_object_only() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let transfer = TransactionData::new_transfer(
+        dbg_addr(1),
+        random_object_ref(),
+        sender,
+        random_object_ref(),
+        10000,
+    );
+    assert!(transfer.is_owned_object_only());
+
+    let shared_call = TransactionData::new_move_call(
+        sender,
+        random_object_ref(),
+        Identifier::new("counter").unwrap(),
+        Identifier::new("increment").unwrap(),
+        vec![],
+        random_object_ref(),
+        vec![CallArg::Object(ObjectArg::SharedObject(ObjectID::random()))],
+        10000,
+    );
+    assert!(!shared_call.is_owned_object_only());
+
+    let (_, authority_key): (_, AuthorityKeyPair) = get_key_pair();
+    let change_epoch =
+        SignedTransaction::new_change_epoch(1, 0, 0, authority_key.public().into(), &authority_key);
+    assert!(!change_epoch.signed_data.data.is_owned_object_only());
+}
+
+#[test]
+fn test_consensus_transaction_verify_in_epoch() {
+    let (keys, committee) = make_committee_key(&mut StdRng::from_seed([0; 32]));
+    let certificate = make_certificate_signed_by(&keys[..3], &committee);
+    let message =
+        ConsensusTransaction::new_certificate_message(&keys[0].public().into(), certificate);
+
+    assert!(message.verify_in_epoch(&committee, committee.epoch).is_ok());
+    assert_eq!(
+        message.verify_in_epoch(&committee, committee.epoch + 1),
+        Err(SuiError::WrongEpoch {
+            expected_epoch: committee.epoch + 1,
+            actual_epoch: committee.epoch,
+        })
+    );
+}
+
+#[test]
+fn test_effects_canonical_bytes() {
+    let owner = Owner::AddressOwner(dbg_addr(1));
+    let (created, deleted) = (
+        vec![(random_object_ref(), owner), (random_object_ref(), owner)],
+        vec![random_object_ref(), random_object_ref()],
+    );
+    let gas_object = (random_object_ref(), owner);
+    let effects = |created: Vec<_>, deleted: Vec<_>| {
+        TransactionEffectsBuilder::new()
+            .transaction_digest(TransactionDigest::genesis())
+            .gas_object(gas_object)
+            .created(created)
+            .deleted(deleted)
+            .build()
+    };
+
+    let forward = effects(created.clone(), deleted.clone());
+    let reversed = effects(
+        created.into_iter().rev().collect(),
+        deleted.into_iter().rev().collect(),
+    );
+    assert_ne!(
+        bcs::to_bytes(&forward).unwrap(),
+        bcs::to_bytes(&reversed).unwrap()
+    );
+    assert_eq!(forward.canonical_bytes(), reversed.canonical_bytes());
+
+    // Effects that differ in content still differ.
+    let other = effects(vec![(random_object_ref(), owner)], vec![]);
+    assert_ne!(forward.canonical_bytes(), other.canonical_bytes());
+}
+
+#[test]
+fn test_created_shared_objects() {
+    let shared = random_object_ref();
+    let effects = TransactionEffectsBuilder::new()
+        .created(vec![
+            (random_object_ref(), Owner::AddressOwner(dbg_addr(1))),
+            (shared, Owner::Shared),
+        ])
+        .mutated(vec![(random_object_ref(), Owner::Shared)])
+        .build();
+
+    assert_eq!(effects.created_shared_objects(), vec![shared]);
+}
+
+#[test]
+fn test_batch_validation_errors() {
+    let transfer = SingleTransactionKind::TransferObject(TransferObject {
+        recipient: dbg_addr(1),
+        object_ref: random_object_ref(),
+    });
+    let transfer_sui = SingleTransactionKind::TransferSui(TransferSui {
+        recipient: dbg_addr(1),
+        amount: None,
+    });
+    let change_epoch = SingleTransactionKind::ChangeEpoch(ChangeEpoch {
+        epoch: 1,
+        storage_charge: 0,
+        computation_charge: 0,
+    });
+    let check =
+        |batch: Vec<SingleTransactionKind>| match TransactionKind::Batch(batch).validity_check() {
+            Err(SuiError::InvalidBatchTransaction { error }) => error,
+            other => panic!("Unexpected result {:?}", other),
+        };
+
+    assert_eq!(check(vec![]), BatchValidationError::Empty);
+    assert_eq!(
+        check(vec![
+            transfer.clone(),
+            transfer.clone(),
+            transfer_sui.clone()
+        ]),
+        BatchValidationError::ContainsNonBatchable(2)
+    );
+    // A system transaction is reported even if it comes after a non-batchable one.
+    assert_eq!(
+        check(vec![transfer_sui, change_epoch]),
+        BatchValidationError::ContainsSystemTx(1)
+    );
+    assert_eq!(
+        check(vec![transfer.clone(); MAX_TX_BATCH_SIZE + 1]),
+        BatchValidationError::TooLarge {
+            size: MAX_TX_BATCH_SIZE + 1,
+            max: MAX_TX_BATCH_SIZE,
+        }
+    );
+    assert!(TransactionKind::Batch(vec![transfer; MAX_TX_BATCH_SIZE])
+        .validity_check()
+        .is_ok());
+
+    // The structured error still renders a readable message.
+    assert_eq!(
+        SuiError::InvalidBatchTransaction {
+            error: BatchValidationError::ContainsNonBatchable(2)
+        }
+        .to_string(),
+        "Invalid Batch Transaction: Transaction 2 of the batch cannot be batched. Only Call, TransferObject and Pay are allowed"
+    );
+}
+
+#[test]
+fn test_transaction_data_syntactic_check() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let gas = random_object_ref();
+    let data = |kind, gas_budget| TransactionData::new(kind, sender, gas, gas_budget);
+    let single = TransactionKind::Single;
+    let transfer = SingleTransactionKind::TransferObject(TransferObject {
+        recipient: dbg_addr(1),
+        object_ref: random_object_ref(),
+    });
+    let pay = |coins, recipients, amounts| {
+        single(SingleTransactionKind::Pay(Pay {
+            coins,
+            recipients,
+            amounts,
+        }))
+    };
+    let publish = |modules| {
+        single(SingleTransactionKind::Publish(MoveModulePublish {
+            modules,
+        }))
+    };
+
+    assert!(data(single(transfer.clone()), 10000)
+        .syntactic_check()
+        .is_ok());
+    assert!(data(
+        pay(vec![random_object_ref()], vec![dbg_addr(1)], vec![1]),
+        10000
+    )
+    .syntactic_check()
+    .is_ok());
+
+    // The kind must pass validity_check.
+    assert!(matches!(
+        data(TransactionKind::Batch(vec![]), 10000).syntactic_check(),
+        Err(SuiError::InvalidBatchTransaction { .. })
+    ));
+
+    // Pay arity.
+    for kind in [
+        pay(vec![], vec![dbg_addr(1)], vec![1]),
+        pay(vec![random_object_ref()], vec![], vec![]),
+        pay(vec![random_object_ref()], vec![dbg_addr(1)], vec![1, 2]),
+    ] {
+        assert!(matches!(
+            data(kind, 10000).syntactic_check(),
+            Err(SuiError::InvalidPayTransaction { .. })
+        ));
+    }
+    // Arity is checked inside batches too.
+    let batch = TransactionKind::Batch(vec![
+        transfer.clone(),
+        SingleTransactionKind::Pay(Pay {
+            coins: vec![random_object_ref()],
+            recipients: vec![dbg_addr(1), dbg_addr(2)],
+            amounts: vec![1],
+        }),
+    ]);
+    assert!(matches!(
+        data(batch, 10000).syntactic_check(),
+        Err(SuiError::InvalidPayTransaction { .. })
+    ));
+
+    // Publish needs non-empty modules.
+    for modules in [vec![], vec![vec![1, 2, 3], vec![]]] {
+        assert!(matches!(
+            data(publish(modules), 10000).syntactic_check(),
+            Err(SuiError::ModulePublishFailure { .. })
+        ));
+    }
+
+    // Gas budget must be non-zero.
+    assert!(matches!(
+        data(single(transfer), 0).syntactic_check(),
+        Err(SuiError::InsufficientGas { .. })
+    ));
+}
@@ -4,8 +4,14 @@
 use fastcrypto::traits::KeyPair as KeypairTraits;
 
 use crate::{
+    base_types::{
+        ObjectDigest, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
+    },
     committee::Committee,
     crypto::{get_key_pair_from_rng, AuthorityKeyPair, AuthorityPublicKeyBytes},
+    gas::GasCostSummary,
+    messages::{ExecutionStatus, TransactionEffects},
+    object::Owner,
 };
 use std::collections::BTreeMap;
 
@@ -35,3 +41,103 @@ where
     let committee = Committee::new(0, authorities).unwrap();
     (keys, committee)
 }
+
+/// Builds `TransactionEffects` for test fixtures. Unset fields get sensible defaults
+/// (successful status, zero gas, random digest and gas object), and the gas object is
+/// always included in `mutated` so the result is internally consistent.
+pub struct TransactionEffectsBuilder {
+    status: ExecutionStatus,
+    gas_used: GasCostSummary,
+    transaction_digest: TransactionDigest,
+    created: Vec<(ObjectRef, Owner)>,
+    mutated: Vec<(ObjectRef, Owner)>,
+    deleted: Vec<ObjectRef>,
+    gas_object: (ObjectRef, Owner),
+}
+
+impl Default for TransactionEffectsBuilder {
+    fn default() -> Self {
+        Self {
+            status: ExecutionStatus::Success,
+            gas_used: GasCostSummary {
+                computation_cost: 0,
+                storage_cost: 0,
+                storage_rebate: 0,
+            },
+            transaction_digest: TransactionDigest::random(),
+            created: Vec::new(),
+            mutated: Vec::new(),
+            deleted: Vec::new(),
+            gas_object: (
+                (
+                    ObjectID::random(),
+                    SequenceNumber::from_u64(1),
+                    ObjectDigest::random(),
+                ),
+                Owner::AddressOwner(SuiAddress::default()),
+            ),
+        }
+    }
+}
+
+impl TransactionEffectsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn transaction_digest(mut self, transaction_digest: TransactionDigest) -> Self {
+        self.transaction_digest = transaction_digest;
+        self
+    }
+
+    pub fn created(mut self, created: Vec<(ObjectRef, Owner)>) -> Self {
+        self.created = created;
+        self
+    }
+
+    pub fn mutated(mut self, mutated: Vec<(ObjectRef, Owner)>) -> Self {
+        self.mutated = mutated;
+        self
+    }
+
+    pub fn deleted(mut self, deleted: Vec<ObjectRef>) -> Self {
+        self.deleted = deleted;
+        self
+    }
+
+    pub fn status(mut self, status: ExecutionStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn gas_used(mut self, gas_used: GasCostSummary) -> Self {
+        self.gas_used = gas_used;
+        self
+    }
+
+    pub fn gas_object(mut self, gas_object: (ObjectRef, Owner)) -> Self {
+        self.gas_object = gas_object;
+        self
+    }
+
+    pub fn build(self) -> TransactionEffects {
+        let mut mutated = self.mutated;
+        if !mutated.contains(&self.gas_object) {
+            mutated.push(self.gas_object);
+        }
+        TransactionEffects {
+            status: self.status,
+            gas_used: self.gas_used,
+            shared_objects: Vec::new(),
+            transaction_digest: self.transaction_digest,
+            created: self.created,
+            mutated,
+            unwrapped: Vec::new(),
+            deleted: self.deleted,
+            wrapped: Vec::new(),
+            gas_object: self.gas_object,
+            events: Vec::new(),
+            dependencies: Vec::new(),
+        }
+    }
+}
@@ -2,6 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use futures::stream::StreamExt;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClientBuilder;
+use jsonrpsee::rpc_params;
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 use sui_config::NetworkConfig;
@@ -10,6 +13,7 @@ use sui_core::{
     authority_aggregator::{AuthorityAggregator, AuthorityAggregatorBuilder},
     authority_client::NetworkAuthorityClient,
 };
+use sui_json_rpc_types::EffectsPage;
 use sui_macros::sim_test;
 use sui_node::SuiNodeHandle;
 use sui_sdk::crypto::{InMemKeystore, Keystore};
@@ -359,3 +363,69 @@ async fn checkpoint_with_shared_objects() {
 
     wait_for_advance_to_next_checkpoint(&handles, &transaction_digests).await;
 }
+
+// Not a sim_test: the simulator cannot run the JSON-RPC server.
+#[tokio::test]
+async fn replay_effects_since_checkpoint() {
+    telemetry_subscribers::init_for_testing();
+    // Make a few test transactions.
+    let total_transactions = 3;
+    let keys = Keystore::from(InMemKeystore::new(total_transactions));
+
+    let (transactions, input_objects) = make_transactions_with_pre_genesis_objects(keys);
+    let transaction_digests: HashSet<_> = transactions.iter().map(|x| *x.digest()).collect();
+
+    // Spawn a quorum of authorities, the first one serving the read-only JSON-RPC API.
+    let mut configs = test_authority_configs();
+    configs.validator_configs[0].validator_enable_read_rpc = true;
+    let rpc_url = format!("http://{}", configs.validator_configs[0].json_rpc_address);
+    let handles = spawn_test_authorities(input_objects, &configs).await;
+    let aggregator = make_aggregator(&configs, &handles);
+
+    spawn_checkpoint_processes(&configs, &handles).await;
+    execute_transactions(&aggregator, &transactions).await;
+    wait_for_advance_to_next_checkpoint(&handles, &transaction_digests).await;
+
+    // Wait for a second checkpoint to be stored.
+    let state = handles[0].with(|node| node.state());
+    let mut cnt = 0;
+    while state
+        .checkpoints()
+        .lock()
+        .tables
+        .checkpoint_contents
+        .get(&1)
+        .unwrap()
+        .is_none()
+    {
+        sleep(Duration::from_secs(1)).await;
+        cnt += 1;
+        assert!(cnt <= 60);
+    }
+
+    // Replay all effects from the first checkpoint over JSON-RPC, following the cursor.
+    let client = HttpClientBuilder::default().build(&rpc_url).unwrap();
+    let mut replayed = HashSet::new();
+    let mut cursor = 0u64;
+    loop {
+        let page: EffectsPage = client
+            .request(
+                "sui_getEffectsSinceCheckpoint",
+                rpc_params![cursor, Some(1usize)],
+            )
+            .await
+            .unwrap();
+        let next = page.next_cursor.unwrap();
+        replayed.extend(page.data.iter().map(|e| e.transaction_digest));
+        if page.data.is_empty() {
+            // Caught up: the cursor stays put so that polling can resume from it.
+            assert_eq!(next, cursor);
+            break;
+        }
+        assert!(next > cursor);
+        cursor = next;
+    }
+
+    assert!(replayed.is_superset(&transaction_digests));
+    assert!(replayed.is_subset(&transactions_in_checkpoint(&state)));
+}
@@ -6,7 +6,9 @@ use std::{collections::BTreeMap, sync::Arc};
 
 use futures::future;
 use jsonrpsee::core::client::{ClientT, Subscription, SubscriptionClientT};
+use jsonrpsee::http_client::HttpClientBuilder;
 use jsonrpsee::rpc_params;
+use jsonrpsee::ws_client::WsClientBuilder;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::ModuleId;
@@ -17,30 +19,37 @@ use tokio::time::timeout;
 use tokio::time::{sleep, Duration};
 
 use sui::client_commands::{SuiClientCommandResult, SuiClientCommands};
+use sui_config::utils::get_available_port;
 use sui_json_rpc_types::{
-    SuiEvent, SuiEventEnvelope, SuiEventFilter, SuiExecuteTransactionResponse, SuiExecutionStatus,
-    SuiMoveStruct, SuiMoveValue, SuiTransactionFilter, SuiTransactionResponse,
+    GetObjectDataResponse, SuiEvent, SuiEventEnvelope, SuiEventFilter,
+    SuiExecuteTransactionResponse, SuiExecutionStatus, SuiMoveNormalizedModule, SuiMoveStruct,
+    SuiMoveValue, SuiObjectWithLayout, SuiTransactionFilter, SuiTransactionResponse,
 };
 use sui_macros::*;
 use sui_node::SuiNode;
 use sui_sdk::crypto::AccountKeystore;
 use sui_types::base_types::{ObjectRef, SequenceNumber};
+use sui_types::error::SuiError;
 use sui_types::event::TransferType;
 use sui_types::messages::{
-    ExecuteTransactionRequest, ExecuteTransactionRequestType, ExecuteTransactionResponse,
+    CommitteeInfoResponse, ExecuteTransactionRequest, ExecuteTransactionRequestType,
+    ExecuteTransactionResponse,
 };
-use sui_types::object::{Object, ObjectRead, Owner, PastObjectRead};
+use sui_types::object::{Object, ObjectFormatOptions, ObjectRead, Owner, PastObjectRead};
 use sui_types::query::TransactionQuery;
 use sui_types::sui_framework_address_concat_string;
 use sui_types::{
     base_types::{ObjectID, SuiAddress, TransactionDigest},
     messages::TransactionInfoRequest,
+    SUI_FRAMEWORK_OBJECT_ID,
 };
 use test_utils::authority::test_and_configure_authority_configs;
-use test_utils::messages::make_transactions_with_wallet_context;
 use test_utils::messages::{
     get_gas_object_with_wallet_context, make_transfer_object_transaction_with_wallet_context,
 };
+use test_utils::messages::{
+    make_random_certified_transaction, make_transactions_with_wallet_context,
+};
 use test_utils::network::{
     init_cluster_builder_env_aware, start_a_fullnode, start_a_fullnode_with_handle,
 };
@@ -879,6 +888,21 @@ async fn test_full_node_transaction_orchestrator_basic() -> Result<(), anyhow::E
     Ok(())
 }
 
+#[tokio::test]
+async fn test_all_known_committees_at_genesis() {
+    let configs = test_and_configure_authority_configs(1);
+    let validator_config = &configs.validator_configs()[0];
+    let node = SuiNode::start(validator_config, Registry::new())
+        .await
+        .unwrap();
+
+    let committee = configs.committee();
+    assert_eq!(
+        node.all_known_committees(),
+        vec![(0, committee.voting_rights)]
+    );
+}
+
 /// Test a validator node does not have transaction orchestrator
 #[tokio::test]
 async fn test_validator_node_has_no_transaction_orchestrator() {
@@ -893,6 +917,140 @@ async fn test_validator_node_has_no_transaction_orchestrator() {
         .is_err());
 }
 
+/// Test a validator with read RPC enabled serves read-only queries, but neither full node only
+/// methods nor transaction execution
+#[tokio::test]
+async fn test_validator_node_read_rpc() -> Result<(), anyhow::Error> {
+    let mut configs = test_and_configure_authority_configs(1);
+    let validator_config = &mut configs.validator_configs[0];
+    validator_config.validator_enable_read_rpc = true;
+    let _node = SuiNode::start(validator_config, Registry::new()).await?;
+
+    let rpc_url = format!("http://{}", validator_config.json_rpc_address);
+    let jsonrpc_client = HttpClientBuilder::default().build(&rpc_url)?;
+
+    let response: CommitteeInfoResponse = jsonrpc_client
+        .request("sui_getCommitteeInfo", rpc_params![0u64])
+        .await?;
+    assert_eq!(response.epoch, 0);
+    assert!(response.committee_info.is_some());
+
+    // Full node only methods are not served.
+    let role_error = SuiError::EndpointNotAvailableOnRole { role: "validator" }.to_string();
+    let err = jsonrpc_client
+        .request::<SuiMoveNormalizedModule>(
+            "sui_getNormalizedMoveModule",
+            rpc_params![SUI_FRAMEWORK_OBJECT_ID, "coin"],
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains(&role_error), "{err}");
+
+    let txn = make_random_certified_transaction().to_transaction();
+    let (tx_bytes, flag, signature, pub_key) = txn.to_network_data_for_execution();
+    let params = rpc_params![
+        tx_bytes,
+        flag,
+        signature,
+        pub_key,
+        ExecuteTransactionRequestType::ImmediateReturn
+    ];
+    let err = jsonrpc_client
+        .request::<SuiExecuteTransactionResponse>("sui_executeTransaction", params)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains(&role_error), "{err}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_subscriptions_per_connection() -> Result<(), anyhow::Error> {
+    let test_cluster = init_cluster_builder_env_aware().build().await?;
+
+    let max_subscriptions = 2;
+    let mut config = test_cluster
+        .swarm
+        .config()
+        .generate_fullnode_config_with_random_dir_name(true, false);
+    config.json_rpc_address = format!("127.0.0.1:{}", get_available_port()).parse()?;
+    let ws_addr = format!("127.0.0.1:{}", get_available_port());
+    config.websocket_address = Some(ws_addr.parse()?);
+    config.max_subscriptions_per_connection = Some(max_subscriptions);
+    let _node = SuiNode::start(&config, Registry::new()).await?;
+
+    let ws_client = WsClientBuilder::default()
+        .build(&format!("ws://{}", ws_addr))
+        .await?;
+    let mut subs = Vec::new();
+    for _ in 0..max_subscriptions {
+        let sub: Subscription<SuiEventEnvelope> = ws_client
+            .subscribe(
+                "sui_subscribeEvent",
+                rpc_params![SuiEventFilter::All(vec![])],
+                "sui_unsubscribeEvent",
+            )
+            .await?;
+        subs.push(sub);
+    }
+
+    // One more subscription on the same connection is over the limit.
+    let result: Result<Subscription<SuiEventEnvelope>, _> = ws_client
+        .subscribe(
+            "sui_subscribeEvent",
+            rpc_params![SuiEventFilter::All(vec![])],
+            "sui_unsubscribeEvent",
+        )
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_preload_packages() -> Result<(), anyhow::Error> {
+    let test_cluster = init_cluster_builder_env_aware().build().await?;
+
+    let framework = ObjectID::from_hex_literal("0x2").unwrap();
+    let mut config = test_cluster
+        .swarm
+        .config()
+        .generate_fullnode_config_with_random_dir_name(true, false);
+    config.json_rpc_address = format!("127.0.0.1:{}", get_available_port()).parse()?;
+    config.websocket_address = None;
+    config.preload_packages = vec![framework];
+    let node = SuiNode::start(&config, Registry::new()).await?;
+
+    let coin = ModuleId::new(
+        AccountAddress::from(framework),
+        Identifier::from_str("coin").unwrap(),
+    );
+    assert!(node.state().module_cache.get_module_by_id(&coin)?.is_some());
+
+    // Preloading reports every module of the package.
+    let package = node
+        .state()
+        .get_object_read(&framework)
+        .await?
+        .into_object()?;
+    let modules = package
+        .data
+        .try_as_package()
+        .unwrap()
+        .serialized_module_map()
+        .len();
+    assert_eq!(node.state().preload_packages(&[framework]).await?, modules);
+
+    // Packages that do not exist cannot be preloaded.
+    assert!(node
+        .state()
+        .preload_packages(&[ObjectID::random()])
+        .await
+        .is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_full_node_transaction_orchestrator_rpc_ok() -> Result<(), anyhow::Error> {
     let mut test_cluster = init_cluster_builder_env_aware().build().await?;
@@ -1051,6 +1209,78 @@ async fn get_obj_read_from_node(
     }
 }
 
+#[tokio::test]
+async fn test_get_object_with_layout() -> Result<(), anyhow::Error> {
+    let mut test_cluster = init_cluster_builder_env_aware().build().await?;
+    let context = &mut test_cluster.wallet;
+    let node = &test_cluster.fullnode_handle.as_ref().unwrap().sui_node;
+    let jsonrpc_client = &test_cluster.fullnode_handle.as_ref().unwrap().rpc_client;
+
+    let (_, object_id, digest) = create_devnet_nft(context).await?;
+    wait_for_tx(digest, node.state().clone()).await;
+
+    let with_types: SuiObjectWithLayout = jsonrpc_client
+        .request(
+            "sui_getObjectWithLayout",
+            rpc_params![object_id, ObjectFormatOptions::new(true)],
+        )
+        .await?;
+    assert_eq!(with_types.object.object()?.id(), object_id);
+    match with_types.layout {
+        Some(MoveStructLayout::WithTypes { type_, .. }) => {
+            assert_eq!(type_.name.as_str(), "DevNetNFT")
+        }
+        other => anyhow::bail!("Expect a layout with types but got {other:?}."),
+    }
+
+    let with_fields: SuiObjectWithLayout = jsonrpc_client
+        .request(
+            "sui_getObjectWithLayout",
+            rpc_params![object_id, ObjectFormatOptions::new(false)],
+        )
+        .await?;
+    assert!(matches!(
+        with_fields.layout,
+        Some(MoveStructLayout::WithFields(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multi_get_objects() -> Result<(), anyhow::Error> {
+    let mut test_cluster = init_cluster_builder_env_aware().build().await?;
+    let context = &mut test_cluster.wallet;
+    let node = &test_cluster.fullnode_handle.as_ref().unwrap().sui_node;
+    let jsonrpc_client = &test_cluster.fullnode_handle.as_ref().unwrap().rpc_client;
+
+    let (_, first, digest) = create_devnet_nft(context).await?;
+    wait_for_tx(digest, node.state().clone()).await;
+    let (_, second, digest) = create_devnet_nft(context).await?;
+    wait_for_tx(digest, node.state().clone()).await;
+    let missing = ObjectID::random();
+
+    let responses: Vec<GetObjectDataResponse> = jsonrpc_client
+        .request(
+            "sui_multiGetObjects",
+            rpc_params![vec![second, missing, first]],
+        )
+        .await?;
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0].object()?.id(), second);
+    assert!(matches!(responses[1], GetObjectDataResponse::NotExists(id) if id == missing));
+    assert_eq!(responses[2].object()?.id(), first);
+
+    // Requests over the server's batch limit (1000 ids) are rejected.
+    let too_many = vec![first; 1001];
+    let result: Result<Vec<GetObjectDataResponse>, _> = jsonrpc_client
+        .request("sui_multiGetObjects", rpc_params![too_many])
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_get_objects_read() -> Result<(), anyhow::Error> {
     telemetry_subscribers::init_for_testing();
@@ -1139,3 +1369,26 @@ async fn test_get_objects_read() -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+#[sim_test]
+async fn test_subscribe_to_executed_digests() -> Result<(), anyhow::Error> {
+    let mut test_cluster = init_cluster_builder_env_aware().build().await?;
+    let sui_node = start_a_fullnode(&test_cluster.swarm, false).await?;
+    let node = if cfg!(msim) {
+        &sui_node
+    } else {
+        &test_cluster.fullnode_handle.as_ref().unwrap().sui_node
+    };
+    let mut rx = node.subscribe_to_executed_digests()?;
+
+    let context = &mut test_cluster.wallet;
+    let (_, _, _, digest) = transfer_coin(context).await?;
+
+    timeout(Duration::from_secs(10), async {
+        while rx.recv().await.unwrap() != digest {}
+    })
+    .await
+    .expect("Executed digest should be broadcast");
+
+    Ok(())
+}
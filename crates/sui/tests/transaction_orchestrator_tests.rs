@@ -4,16 +4,19 @@
 use prometheus::Registry;
 use sui::client_commands::WalletContext;
 use sui_core::authority_client::NetworkAuthorityClient;
+use sui_core::test_utils::to_sender_signed_transaction;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
 use sui_node::SuiNode;
 use sui_sdk::crypto::AccountKeystore;
 use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress, TransactionDigest};
 use sui_types::messages::{
     ExecuteTransactionRequest, ExecuteTransactionRequestType, ExecuteTransactionResponse,
-    QuorumDriverRequest, QuorumDriverRequestType, Transaction,
+    ExecutionFailureStatus, ExecutionStatus, QuorumDriverRequest, QuorumDriverRequestType,
+    Transaction, TransactionData,
 };
 use test_utils::messages::{
-    make_counter_increment_transaction_with_wallet_context, make_transactions_with_wallet_context,
+    get_gas_objects_with_wallet_context, make_counter_increment_transaction_with_wallet_context,
+    make_transactions_with_wallet_context, MAX_GAS,
 };
 use test_utils::network::{start_a_fullnode_with_handle, TestClusterBuilder};
 use test_utils::transaction::{
@@ -237,6 +240,53 @@ async fn test_local_execution_with_missing_parents() -> Result<(), anyhow::Error
     Ok(())
 }
 
+#[tokio::test]
+async fn test_best_effort_execution_of_failing_transaction() -> Result<(), anyhow::Error> {
+    let test_cluster = TestClusterBuilder::new().build().await?;
+    let context = &test_cluster.wallet;
+    let node = &test_cluster.fullnode_handle.as_ref().unwrap().sui_node;
+
+    let active = node.active();
+    let net = active.agg_aggregator();
+    let node_sync_handle = active.clone().node_sync_handle();
+    let orchestrator =
+        TransactiondOrchestrator::new(net, node.state(), node_sync_handle, &Registry::new());
+
+    let sender = context.config.keystore.addresses().get(0).cloned().unwrap();
+    let gas_objects = get_gas_objects_with_wallet_context(context, &sender).await;
+    // A payment without recipients passes the input checks but fails during execution.
+    let data = TransactionData::new_pay(
+        sender,
+        vec![gas_objects[0].to_object_ref()],
+        vec![],
+        vec![],
+        gas_objects[1].to_object_ref(),
+        MAX_GAS,
+    );
+    let txn = to_sender_signed_transaction(data, context.config.keystore.get_key(&sender).unwrap());
+
+    let res = execute_with_orchestrator(
+        &orchestrator,
+        txn,
+        ExecuteTransactionRequestType::BestEffortWithPartialEffects,
+    )
+    .await;
+    let effects = match res {
+        ExecuteTransactionResponse::EffectsCert(result) => result.1.effects,
+        ExecuteTransactionResponse::PartialEffects(effects) => *effects,
+        other => panic!("Unexpected response: {:?}", other),
+    };
+    assert!(matches!(
+        effects.status,
+        ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::EmptyRecipients
+        }
+    ));
+    assert!(effects.gas_used.computation_cost > 0);
+
+    Ok(())
+}
+
 async fn increment(
     context: &WalletContext,
     signer: &SuiAddress,
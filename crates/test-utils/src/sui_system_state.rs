@@ -86,5 +86,7 @@ pub fn test_sui_system_state(epoch: EpochId, validators: Vec<Validator>) -> SuiS
             storage_gas_price: 1,
         },
         reference_gas_price: 1,
+        consensus_round: 0,
+        consensus_commit_timestamp_ms: 0,
     }
 }